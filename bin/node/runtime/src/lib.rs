@@ -1166,6 +1166,7 @@ impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
 
 parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const RejectionSlashFraction: Permill = Permill::from_percent(100);
 	pub const ProposalBondMinimum: Balance = 1 * DOLLARS;
 	pub const SpendPeriod: BlockNumber = 1 * DAYS;
 	pub const Burn: Permill = Permill::from_percent(50);
@@ -1193,15 +1194,23 @@ impl pallet_treasury::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
 	type ProposalBond = ProposalBond;
+	type RejectionSlashFraction = RejectionSlashFraction;
 	type ProposalBondMinimum = ProposalBondMinimum;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = SpendPeriod;
+	type SpendPeriodOffset = ConstU32<0>;
 	type Burn = Burn;
 	type BurnDestination = ();
 	type SpendFunds = Bounties;
+	type OnSpend = ();
 	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 	type MaxApprovals = MaxApprovals;
 	type SpendOrigin = EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>;
+	type AllowLocalSpend = ConstBool<true>;
+	type MinimumSpend = ExistentialDeposit;
+	type ApprovalExpiry = ();
+	type SlashExpiredApprovals = ConstBool<false>;
+	type EmergencySpendOrigin = EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>;
 }
 
 impl pallet_asset_rate::Config for Runtime {
@@ -2001,6 +2010,7 @@ impl pallet_broker::Config for Runtime {
 	type TimeslicePeriod = ConstU32<2>;
 	type MaxLeasedCores = ConstU32<5>;
 	type MaxReservedCores = ConstU32<5>;
+	type MaxTicksPerCall = ConstU32<4>;
 	type Coretime = CoretimeProvider;
 	type ConvertBalance = traits::Identity;
 	type WeightInfo = ();
@@ -2137,6 +2147,8 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_broker::migration::v1::MigrateToV1<Runtime>,
+	pallet_treasury::migration::v1::MigrateToV1<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2513,6 +2525,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -2531,6 +2544,38 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: NativeOrAssetId<u32>, asset2: NativeOrAssetId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(&asset1, &asset2).ok()
 		}
+
+		fn simulate_add_liquidity(asset1: NativeOrAssetId<u32>, asset2: NativeOrAssetId<u32>, amount1_desired: u128, amount2_desired: u128) -> Option<(u128, u128, u128)> {
+			AssetConversion::simulate_add_liquidity(asset1, asset2, amount1_desired, amount2_desired)
+		}
+
+		fn quote_path(path: Vec<NativeOrAssetId<u32>>, amount_in: u128) -> Option<Vec<u128>> {
+			AssetConversion::quote_path(path, amount_in)
+		}
+	}
+
+	impl pallet_treasury::TreasuryApi<Block> for Runtime {
+		fn approval_position(proposal_id: pallet_treasury::ProposalIndex) -> Option<u32> {
+			Treasury::approval_position(proposal_id)
+		}
+	}
+
+	impl pallet_scheduler::SchedulerApi<Block, BlockNumber> for Runtime {
+		fn next_dispatch_of(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<(BlockNumber, u32)> {
+			Scheduler::next_dispatch_of(id)
+		}
+
+		fn scheduled_weight_at(when: BlockNumber) -> Weight {
+			Scheduler::scheduled_weight_at(when)
+		}
+	}
+
+	impl pallet_broker::BrokerApi<Block, Balance> for Runtime {
+		fn current_price() -> Option<Balance> {
+			Broker::current_price()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -2785,4 +2830,17 @@ mod tests {
 			size,
 		);
 	}
+
+	#[test]
+	fn asset_conversion_api_is_at_version_2() {
+		use sp_api::RuntimeApiInfo;
+		type Api = dyn pallet_asset_conversion::AssetConversionApi<
+			Block,
+			Balance,
+			u128,
+			NativeOrAssetId<u32>,
+		>;
+		// `simulate_add_liquidity` and `quote_path` are only callable from version 2 on.
+		assert!(RUNTIME_API_VERSIONS.iter().any(|(id, version)| *id == Api::ID && *version == 2));
+	}
 }
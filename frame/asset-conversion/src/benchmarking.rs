@@ -334,5 +334,42 @@ benchmarks! {
 		}
 	}
 
+	drain_pool {
+		// Two non-native assets, so that the remaining reserves can be taken out of the pool
+		// without falling below the existential deposit of the native currency.
+		let asset1 = T::BenchmarkHelper::multiasset_id(0);
+		let asset2 = T::BenchmarkHelper::multiasset_id(1);
+		let (lp_token, caller, _) = create_asset_and_pool::<T>(&asset1, &asset2);
+
+		AssetConversion::<T>::add_liquidity(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset1.clone(),
+			asset2.clone(),
+			10000.into(),
+			10000.into(),
+			0.into(),
+			0.into(),
+			caller.clone(),
+		)?;
+		// Every liquidity provider leaves the pool.
+		let lp_amount = T::PoolAssets::balance(lp_token.clone(), &caller);
+		AssetConversion::<T>::remove_liquidity(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset1.clone(),
+			asset2.clone(),
+			lp_amount,
+			0.into(),
+			0.into(),
+			caller.clone(),
+		)?;
+		let pool_id = (asset1.clone(), asset2.clone());
+		let pool_account = AssetConversion::<T>::get_pool_account(&pool_id);
+	}: _(SystemOrigin::Root, asset1, asset2, caller)
+	verify {
+		assert!(!Pools::<T>::contains_key(&pool_id));
+		assert!(!<T::PoolAssets as Inspect<T::AccountId>>::asset_exists(lp_token));
+		assert!(!frame_system::Pallet::<T>::account_exists(&pool_account));
+	}
+
 	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -73,11 +73,11 @@ use frame_support::{
 	traits::tokens::{AssetId, Balance},
 };
 use frame_system::{
-	ensure_signed,
+	ensure_root, ensure_signed,
 	pallet_prelude::{BlockNumberFor, OriginFor},
 };
 pub use pallet::*;
-use sp_arithmetic::traits::Unsigned;
+use sp_arithmetic::{traits::Unsigned, Rounding};
 use sp_runtime::{
 	traits::{
 		CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Ensure, MaybeDisplay, TrailingZeroInput,
@@ -95,7 +95,7 @@ pub mod pallet {
 		pallet_prelude::*,
 		traits::{
 			fungible::{Inspect as InspectFungible, Mutate as MutateFungible},
-			fungibles::{Create, Inspect, Mutate},
+			fungibles::{Create, Destroy, Inspect, Mutate},
 			tokens::{
 				Fortitude::Polite,
 				Precision::Exact,
@@ -168,6 +168,7 @@ pub mod pallet {
 		/// the assets.
 		type PoolAssets: Inspect<Self::AccountId, AssetId = Self::PoolAssetId, Balance = Self::AssetBalance>
 			+ Create<Self::AccountId>
+			+ Destroy<Self::AccountId>
 			+ Mutate<Self::AccountId>
 			+ AccountTouch<Self::PoolAssetId, Self::AccountId>;
 
@@ -302,6 +303,17 @@ pub mod pallet {
 			/// The amount of the asset that was transferred.
 			amount: T::AssetBalance,
 		},
+		/// A pool without liquidity providers has been closed.
+		PoolDrained {
+			/// The pool id of the closed pool.
+			pool_id: PoolIdOf<T>,
+			/// The account that the remaining reserves were transferred to.
+			dest: T::AccountId,
+			/// The amount of the first asset that was left in the pool.
+			amount1: T::AssetBalance,
+			/// The amount of the second asset that was left in the pool.
+			amount2: T::AssetBalance,
+		},
 	}
 
 	#[pallet::error]
@@ -365,6 +377,10 @@ pub mod pallet {
 		/// with another. For example, an array of assets constituting a `path` should have a
 		/// corresponding array of `amounts` along the path.
 		CorrespondenceError,
+		/// The pool exists but no liquidity has been added to it yet.
+		PoolNotInitialized,
+		/// The pool still holds liquidity other than the permanently locked minimum.
+		PoolInUse,
 	}
 
 	#[pallet::hooks]
@@ -493,69 +509,28 @@ pub mod pallet {
 			let pool = maybe_pool.as_ref().ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::get_pool_account(&pool_id);
 
-			let (asset1, asset2) = &pool_id;
-			let reserve1 = Self::get_balance(&pool_account, asset1)?;
-			let reserve2 = Self::get_balance(&pool_account, asset2)?;
-
-			let amount1: T::AssetBalance;
-			let amount2: T::AssetBalance;
-			if reserve1.is_zero() || reserve2.is_zero() {
-				amount1 = amount1_desired;
-				amount2 = amount2_desired;
-			} else {
-				let amount2_optimal = Self::quote(&amount1_desired, &reserve1, &reserve2)?;
-
-				if amount2_optimal <= amount2_desired {
-					ensure!(
-						amount2_optimal >= amount2_min,
-						Error::<T>::AssetTwoDepositDidNotMeetMinimum
-					);
-					amount1 = amount1_desired;
-					amount2 = amount2_optimal;
-				} else {
-					let amount1_optimal = Self::quote(&amount2_desired, &reserve2, &reserve1)?;
-					ensure!(
-						amount1_optimal <= amount1_desired,
-						Error::<T>::OptimalAmountLessThanDesired
-					);
-					ensure!(
-						amount1_optimal >= amount1_min,
-						Error::<T>::AssetOneDepositDidNotMeetMinimum
-					);
-					amount1 = amount1_optimal;
-					amount2 = amount2_desired;
-				}
-			}
-
-			Self::validate_minimal_amount(amount1.saturating_add(reserve1), asset1)
-				.map_err(|_| Error::<T>::AmountOneLessThanMinimal)?;
-			Self::validate_minimal_amount(amount2.saturating_add(reserve2), asset2)
-				.map_err(|_| Error::<T>::AmountTwoLessThanMinimal)?;
+			let (amount1, amount2, lp_token_amount) = Self::calc_add_liquidity(
+				&pool_id,
+				&pool.lp_token,
+				amount1_desired,
+				amount2_desired,
+				amount1_min,
+				amount2_min,
+			)?;
+			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
 
+			let (asset1, asset2) = &pool_id;
 			Self::transfer(asset1, &sender, &pool_account, amount1, true)?;
 			Self::transfer(asset2, &sender, &pool_account, amount2, true)?;
 
-			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
-
-			let lp_token_amount: T::AssetBalance;
 			if total_supply.is_zero() {
-				lp_token_amount = Self::calc_lp_amount_for_zero_supply(&amount1, &amount2)?;
 				T::PoolAssets::mint_into(
 					pool.lp_token.clone(),
 					&pool_account,
 					T::MintMinLiquidity::get(),
 				)?;
-			} else {
-				let side1 = Self::mul_div(&amount1, &total_supply, &reserve1)?;
-				let side2 = Self::mul_div(&amount2, &total_supply, &reserve2)?;
-				lp_token_amount = side1.min(side2);
 			}
 
-			ensure!(
-				lp_token_amount > T::MintMinLiquidity::get(),
-				Error::<T>::InsufficientLiquidityMinted
-			);
-
 			T::PoolAssets::mint_into(pool.lp_token.clone(), &mint_to, lp_token_amount)?;
 
 			Self::deposit_event(Event::LiquidityAdded {
@@ -574,6 +549,10 @@ pub mod pallet {
 		/// Allows you to remove liquidity by providing the `lp_token_burn` tokens that will be
 		/// burned in the process. With the usage of `amount1_min_receive`/`amount2_min_receive`
 		/// it's possible to control the min amount of returned tokens you're happy with.
+		///
+		/// The share of the reserves backing the permanently locked `MintMinLiquidity`, as well as
+		/// the withdrawal fee and rounding dust, always stays in the pool. Once every liquidity
+		/// provider has left, Root may sweep it with [`Pallet::drain_pool`].
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::remove_liquidity())]
 		pub fn remove_liquidity(
@@ -602,30 +581,13 @@ pub mod pallet {
 			let pool = maybe_pool.as_ref().ok_or(Error::<T>::PoolNotFound)?;
 
 			let pool_account = Self::get_pool_account(&pool_id);
-			let reserve1 = Self::get_balance(&pool_account, &asset1)?;
-			let reserve2 = Self::get_balance(&pool_account, &asset2)?;
-
-			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
-			let withdrawal_fee_amount = T::LiquidityWithdrawalFee::get() * lp_token_burn;
-			let lp_redeem_amount = lp_token_burn.saturating_sub(withdrawal_fee_amount);
-
-			let amount1 = Self::mul_div(&lp_redeem_amount, &reserve1, &total_supply)?;
-			let amount2 = Self::mul_div(&lp_redeem_amount, &reserve2, &total_supply)?;
-
-			ensure!(
-				!amount1.is_zero() && amount1 >= amount1_min_receive,
-				Error::<T>::AssetOneWithdrawalDidNotMeetMinimum
-			);
-			ensure!(
-				!amount2.is_zero() && amount2 >= amount2_min_receive,
-				Error::<T>::AssetTwoWithdrawalDidNotMeetMinimum
-			);
-			let reserve1_left = reserve1.saturating_sub(amount1);
-			let reserve2_left = reserve2.saturating_sub(amount2);
-			Self::validate_minimal_amount(reserve1_left, &asset1)
-				.map_err(|_| Error::<T>::ReserveLeftLessThanMinimal)?;
-			Self::validate_minimal_amount(reserve2_left, &asset2)
-				.map_err(|_| Error::<T>::ReserveLeftLessThanMinimal)?;
+			let (amount1, amount2) = Self::calc_remove_liquidity(
+				&pool_id,
+				&pool.lp_token,
+				lp_token_burn,
+				amount1_min_receive,
+				amount2_min_receive,
+			)?;
 
 			// burn the provided lp token amount that includes the fee
 			T::PoolAssets::burn_from(pool.lp_token.clone(), &sender, lp_token_burn, Exact, Polite)?;
@@ -702,6 +664,64 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Close the pool of `asset1` and `asset2` once every liquidity provider has left it,
+		/// sending the remaining reserves to `dest`.
+		///
+		/// What remains are the reserves backing the permanently locked `MintMinLiquidity`, the
+		/// withdrawal fees and any rounding dust of [`Pallet::remove_liquidity`]. The locked lp
+		/// tokens are burned, the lp token is destroyed and the pool account is reaped, so that
+		/// the pool may be created again.
+		///
+		/// Must be called by Root.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::drain_pool())]
+		pub fn drain_pool(
+			origin: OriginFor<T>,
+			asset1: T::MultiAssetId,
+			asset2: T::MultiAssetId,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let pool_id = Self::get_pool_id(asset1, asset2);
+			let pool = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::get_pool_account(&pool_id);
+
+			let locked = T::PoolAssets::balance(pool.lp_token.clone(), &pool_account);
+			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
+			ensure!(total_supply == locked, Error::<T>::PoolInUse);
+
+			let (asset1, asset2) = &pool_id;
+			let amount1 = Self::get_balance(&pool_account, asset1)?;
+			let amount2 = Self::get_balance(&pool_account, asset2)?;
+
+			let lp_token = pool.lp_token;
+			if !locked.is_zero() {
+				T::PoolAssets::burn_from(lp_token.clone(), &pool_account, locked, Exact, Polite)?;
+			}
+			if !amount1.is_zero() {
+				Self::transfer(asset1, &pool_account, &dest, amount1, false)?;
+			}
+			if !amount2.is_zero() {
+				Self::transfer(asset2, &pool_account, &dest, amount2, false)?;
+			}
+
+			// Only the pool account is expected to hold an lp token account at this point. Should
+			// anything else be left, the lp token stays in the destroying state and anyone may
+			// finish its destruction through `T::PoolAssets`.
+			T::PoolAssets::start_destroy(lp_token.clone(), None)?;
+			T::PoolAssets::destroy_accounts(lp_token.clone(), 1)?;
+			T::PoolAssets::destroy_approvals(lp_token.clone(), 1)?;
+			let _ = T::PoolAssets::finish_destroy(lp_token);
+
+			// Release the provider reference taken in `create_pool`, reaping the pool account.
+			frame_system::Pallet::<T>::dec_providers(&pool_account)?;
+			Pools::<T>::remove(&pool_id);
+
+			Self::deposit_event(Event::PoolDrained { pool_id, dest, amount1, amount2 });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -971,6 +991,9 @@ pub mod pallet {
 			let balance2 = Self::get_balance(&pool_account, asset2)?;
 
 			if balance1.is_zero() || balance2.is_zero() {
+				if Pools::<T>::contains_key(&pool_id) {
+					Err(Error::<T>::PoolNotInitialized)?;
+				}
 				Err(Error::<T>::PoolNotFound)?;
 			}
 
@@ -1062,6 +1085,210 @@ pub mod pallet {
 			}
 		}
 
+		/// Used by the RPC service to preview a swap of `amount_in` along `path`.
+		///
+		/// Returns the amount at each step of the path, starting with `amount_in`, or `None` if
+		/// the path is invalid, any pool on it is missing or any step would yield nothing.
+		pub fn quote_path(
+			path: Vec<T::MultiAssetId>,
+			amount_in: T::AssetBalance,
+		) -> Option<Vec<T::AssetBalance>> {
+			let path: BoundedVec<_, T::MaxSwapPathLength> = path.try_into().ok()?;
+			Self::validate_swap_path(&path).ok()?;
+			let amounts = Self::get_amounts_out(&amount_in, &path).ok()?;
+			if amounts.iter().any(Zero::is_zero) {
+				return None
+			}
+			Some(amounts)
+		}
+
+		/// Used by the RPC service to preview the outcome of [`Pallet::add_liquidity`].
+		///
+		/// Returns the amounts of `asset1` and `asset2` which would be provided to the pool and the
+		/// amount of lp tokens which would be minted, or `None` if the liquidity could not be
+		/// added. The minimum amounts and the balances of the depositor are not taken into
+		/// account.
+		pub fn simulate_add_liquidity(
+			asset1: T::MultiAssetId,
+			asset2: T::MultiAssetId,
+			amount1_desired: T::AssetBalance,
+			amount2_desired: T::AssetBalance,
+		) -> Option<(T::AssetBalance, T::AssetBalance, T::AssetBalance)> {
+			let pool_id = Self::get_pool_id(asset1.clone(), asset2.clone());
+			let swapped = pool_id.0 != asset1;
+			let (amount1_desired, amount2_desired) = if swapped {
+				(amount2_desired, amount1_desired)
+			} else {
+				(amount1_desired, amount2_desired)
+			};
+			if amount1_desired.is_zero() || amount2_desired.is_zero() {
+				return None
+			}
+
+			let pool = Pools::<T>::get(&pool_id)?;
+			let (amount1, amount2, lp_token_amount) = Self::calc_add_liquidity(
+				&pool_id,
+				&pool.lp_token,
+				amount1_desired,
+				amount2_desired,
+				Zero::zero(),
+				Zero::zero(),
+			)
+			.ok()?;
+
+			if swapped {
+				Some((amount2, amount1, lp_token_amount))
+			} else {
+				Some((amount1, amount2, lp_token_amount))
+			}
+		}
+
+		/// Calculates the amounts of the assets of `pool_id` to provide for the desired amounts,
+		/// and the amount of `lp_token` to mint in exchange, without modifying storage.
+		///
+		/// All amounts are ordered like the assets of `pool_id`.
+		fn calc_add_liquidity(
+			pool_id: &PoolIdOf<T>,
+			lp_token: &T::PoolAssetId,
+			amount1_desired: T::AssetBalance,
+			amount2_desired: T::AssetBalance,
+			amount1_min: T::AssetBalance,
+			amount2_min: T::AssetBalance,
+		) -> Result<(T::AssetBalance, T::AssetBalance, T::AssetBalance), Error<T>> {
+			let pool_account = Self::get_pool_account(pool_id);
+
+			let (asset1, asset2) = pool_id;
+			let reserve1 = Self::get_balance(&pool_account, asset1)?;
+			let reserve2 = Self::get_balance(&pool_account, asset2)?;
+
+			let amount1: T::AssetBalance;
+			let amount2: T::AssetBalance;
+			if reserve1.is_zero() || reserve2.is_zero() {
+				amount1 = amount1_desired;
+				amount2 = amount2_desired;
+			} else {
+				let amount2_optimal = Self::quote(&amount1_desired, &reserve1, &reserve2)?;
+
+				if amount2_optimal <= amount2_desired {
+					ensure!(
+						amount2_optimal >= amount2_min,
+						Error::<T>::AssetTwoDepositDidNotMeetMinimum
+					);
+					amount1 = amount1_desired;
+					amount2 = amount2_optimal;
+				} else {
+					let amount1_optimal = Self::quote(&amount2_desired, &reserve2, &reserve1)?;
+					ensure!(
+						amount1_optimal <= amount1_desired,
+						Error::<T>::OptimalAmountLessThanDesired
+					);
+					ensure!(
+						amount1_optimal >= amount1_min,
+						Error::<T>::AssetOneDepositDidNotMeetMinimum
+					);
+					amount1 = amount1_optimal;
+					amount2 = amount2_desired;
+				}
+			}
+
+			Self::validate_minimal_amount(amount1.saturating_add(reserve1), asset1)
+				.map_err(|_| Error::<T>::AmountOneLessThanMinimal)?;
+			Self::validate_minimal_amount(amount2.saturating_add(reserve2), asset2)
+				.map_err(|_| Error::<T>::AmountTwoLessThanMinimal)?;
+
+			let total_supply = T::PoolAssets::total_issuance(lp_token.clone());
+
+			let lp_token_amount: T::AssetBalance;
+			if total_supply.is_zero() {
+				lp_token_amount = Self::calc_lp_amount_for_zero_supply(&amount1, &amount2)?;
+			} else {
+				let side1 = Self::mul_div(&amount1, &total_supply, &reserve1)?;
+				let side2 = Self::mul_div(&amount2, &total_supply, &reserve2)?;
+				lp_token_amount = side1.min(side2);
+			}
+
+			ensure!(
+				lp_token_amount > T::MintMinLiquidity::get(),
+				Error::<T>::InsufficientLiquidityMinted
+			);
+
+			Ok((amount1, amount2, lp_token_amount))
+		}
+
+		/// Calculates the amounts of the assets of `pool_id` which burning `lp_token_burn` of
+		/// `lp_token` pays out, without modifying storage.
+		///
+		/// The withdrawal fee is deducted from `lp_token_burn` first. The permanently locked
+		/// `MintMinLiquidity` is part of the total supply, so its share of the reserves is never
+		/// paid out. All amounts are ordered like the assets of `pool_id`.
+		fn calc_remove_liquidity(
+			pool_id: &PoolIdOf<T>,
+			lp_token: &T::PoolAssetId,
+			lp_token_burn: T::AssetBalance,
+			amount1_min_receive: T::AssetBalance,
+			amount2_min_receive: T::AssetBalance,
+		) -> Result<(T::AssetBalance, T::AssetBalance), Error<T>> {
+			let pool_account = Self::get_pool_account(pool_id);
+			let (asset1, asset2) = pool_id;
+			let reserve1 = Self::get_balance(&pool_account, asset1)?;
+			let reserve2 = Self::get_balance(&pool_account, asset2)?;
+
+			let total_supply = T::PoolAssets::total_issuance(lp_token.clone());
+			let withdrawal_fee_amount = T::LiquidityWithdrawalFee::get() * lp_token_burn;
+			let lp_redeem_amount = lp_token_burn.saturating_sub(withdrawal_fee_amount);
+
+			let amount1 = Self::mul_div(&lp_redeem_amount, &reserve1, &total_supply)?;
+			let amount2 = Self::mul_div(&lp_redeem_amount, &reserve2, &total_supply)?;
+
+			ensure!(
+				!amount1.is_zero() && amount1 >= amount1_min_receive,
+				Error::<T>::AssetOneWithdrawalDidNotMeetMinimum
+			);
+			ensure!(
+				!amount2.is_zero() && amount2 >= amount2_min_receive,
+				Error::<T>::AssetTwoWithdrawalDidNotMeetMinimum
+			);
+			let reserve1_left = reserve1.saturating_sub(amount1);
+			let reserve2_left = reserve2.saturating_sub(amount2);
+			Self::validate_minimal_amount(reserve1_left, asset1)
+				.map_err(|_| Error::<T>::ReserveLeftLessThanMinimal)?;
+			Self::validate_minimal_amount(reserve2_left, asset2)
+				.map_err(|_| Error::<T>::ReserveLeftLessThanMinimal)?;
+
+			Ok((amount1, amount2))
+		}
+
+		/// Returns the amounts of `asset1` and `asset2` which [`Pallet::remove_liquidity`] would
+		/// pay out for burning `lp_amount` lp tokens of their pool, or `None` if the removal
+		/// would fail.
+		///
+		/// The withdrawal fee and the share of the permanently locked `MintMinLiquidity` are
+		/// accounted for, so the result is what the liquidity provider actually receives.
+		pub fn redeemable(
+			asset1: T::MultiAssetId,
+			asset2: T::MultiAssetId,
+			lp_amount: T::AssetBalance,
+		) -> Option<(T::AssetBalance, T::AssetBalance)> {
+			if lp_amount.is_zero() {
+				return None
+			}
+			let pool_id = Self::get_pool_id(asset1.clone(), asset2);
+			let pool = Pools::<T>::get(&pool_id)?;
+			let (amount1, amount2) = Self::calc_remove_liquidity(
+				&pool_id,
+				&pool.lp_token,
+				lp_amount,
+				Zero::zero(),
+				Zero::zero(),
+			)
+			.ok()?;
+			if pool_id.0 == asset1 {
+				Some((amount1, amount2))
+			} else {
+				Some((amount2, amount1))
+			}
+		}
+
 		/// Calculates the optimal amount from the reserves.
 		pub fn quote(
 			amount: &T::AssetBalance,
@@ -1107,6 +1334,29 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
+		/// Divides `numerator` by `denominator`, rounding the result up or down as given by
+		/// `rounding`. Only `Rounding::Up` and `Rounding::Down` are supported.
+		fn div_round(
+			numerator: T::HigherPrecisionBalance,
+			denominator: T::HigherPrecisionBalance,
+			rounding: Rounding,
+		) -> Result<T::HigherPrecisionBalance, Error<T>> {
+			debug_assert!(matches!(rounding, Rounding::Up | Rounding::Down));
+			let quotient = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+			if !matches!(rounding, Rounding::Up) {
+				return Ok(quotient)
+			}
+			let remainder = quotient
+				.checked_mul(&denominator)
+				.and_then(|q| numerator.checked_sub(&q))
+				.ok_or(Error::<T>::Overflow)?;
+			if remainder.is_zero() {
+				Ok(quotient)
+			} else {
+				quotient.checked_add(&One::one()).ok_or(Error::<T>::Overflow)
+			}
+		}
+
 		/// Calculates amount out.
 		///
 		/// Given an input amount of an asset and pair reserves, returns the maximum output amount
@@ -1137,7 +1387,9 @@ pub mod pallet {
 				.checked_add(&amount_in_with_fee)
 				.ok_or(Error::<T>::Overflow)?;
 
-			let result = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+			// Round down, in favour of the pool: the user never receives more than the
+			// constant-product curve allows.
+			let result = Self::div_round(numerator, denominator, Rounding::Down)?;
 
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
@@ -1175,11 +1427,9 @@ pub mod pallet {
 				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - T::LPFee::get().into()))
 				.ok_or(Error::<T>::Overflow)?;
 
-			let result = numerator
-				.checked_div(&denominator)
-				.ok_or(Error::<T>::Overflow)?
-				.checked_add(&One::one())
-				.ok_or(Error::<T>::Overflow)?;
+			// Round up, in favour of the pool: the user never pays less than the constant-product
+			// curve requires, and pays nothing extra when the division is exact.
+			let result = Self::div_round(numerator, denominator, Rounding::Up)?;
 
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
@@ -1304,6 +1554,20 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Provides a preview of [`Pallet::add_liquidity`]: the amounts of `asset1` and `asset2`
+		/// which would be provided and the amount of lp tokens which would be minted.
+		///
+		/// Note that the outcome may have changed by the time the transaction is executed.
+		#[api_version(2)]
+		fn simulate_add_liquidity(asset1: AssetId, asset2: AssetId, amount1_desired: AssetBalance, amount2_desired: AssetBalance) -> Option<(AssetBalance, AssetBalance, AssetBalance)>;
+
+		/// Provides the amounts at each step of a [`Pallet::swap_exact_tokens_for_tokens`] along
+		/// `path`, starting with `amount_in`.
+		///
+		/// Note that the outcome may have changed by the time the transaction is executed.
+		#[api_version(2)]
+		fn quote_path(path: Vec<AssetId>, amount_in: AssetBalance) -> Option<Vec<AssetBalance>>;
 	}
 }
 
@@ -19,7 +19,7 @@ use crate::{mock::*, *};
 use frame_support::{
 	assert_noop, assert_ok,
 	instances::Instance1,
-	traits::{fungible::Inspect, fungibles::InspectEnumerable, Get},
+	traits::{fungible::Inspect, fungibles, fungibles::InspectEnumerable, Get},
 };
 use sp_arithmetic::Permill;
 use sp_runtime::{DispatchError, TokenError};
@@ -341,6 +341,74 @@ fn can_add_liquidity() {
 	});
 }
 
+#[test]
+fn simulate_add_liquidity_matches_add_liquidity() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		let pool_id = (token_1, token_2);
+
+		create_tokens(user, vec![token_2]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert_eq!(AssetConversion::simulate_add_liquidity(token_1, token_2, 0, 10), None);
+
+		let ed = get_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+
+		// The first deposit is taken as is.
+		assert_eq!(
+			AssetConversion::simulate_add_liquidity(token_1, token_2, 10000, 10),
+			Some((10000, 10, 216))
+		);
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			10000,
+			10,
+			10000,
+			10,
+			user,
+		));
+		assert!(events().contains(&Event::<Test>::LiquidityAdded {
+			who: user,
+			mint_to: user,
+			pool_id,
+			amount1_provided: 10000,
+			amount2_provided: 10,
+			lp_token,
+			lp_token_minted: 216,
+		}));
+
+		// Later deposits are matched to the pool's ratio, in the order the assets are given.
+		let (amount2, amount1, lp_token_minted) =
+			AssetConversion::simulate_add_liquidity(token_2, token_1, 10, 5000).unwrap();
+		assert_eq!((amount1, amount2), (5000, 5));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_2,
+			token_1,
+			10,
+			5000,
+			1,
+			1,
+			user,
+		));
+		assert!(events().contains(&Event::<Test>::LiquidityAdded {
+			who: user,
+			mint_to: user,
+			pool_id,
+			amount1_provided: amount1,
+			amount2_provided: amount2,
+			lp_token,
+			lp_token_minted,
+		}));
+	});
+}
+
 #[test]
 fn add_tiny_liquidity_leads_to_insufficient_liquidity_minted_error() {
 	new_test_ext().execute_with(|| {
@@ -493,6 +561,186 @@ fn can_remove_liquidity() {
 	});
 }
 
+#[test]
+fn drain_pool_closes_a_pool_without_liquidity_providers() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let dest = 5;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		let pool_id = (token_1, token_2);
+
+		create_tokens(user, vec![token_2]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000000000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 100000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			1000000000,
+			100000,
+			1000000000,
+			100000,
+			user,
+		));
+		LiquidityWithdrawalFee::set(&Permill::from_percent(10));
+
+		assert_noop!(
+			AssetConversion::drain_pool(RuntimeOrigin::signed(user), token_1, token_2, dest),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetConversion::drain_pool(RuntimeOrigin::root(), token_1, token_2, dest),
+			Error::<Test>::PoolInUse
+		);
+
+		// The last liquidity provider cannot take the reserves backing the locked lp tokens, nor
+		// the withdrawal fee.
+		let lp = pool_balance(user, lp_token);
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			lp,
+			0,
+			0,
+			user,
+		));
+		let pool_account = AssetConversion::get_pool_account(&pool_id);
+		assert_eq!(balance(pool_account, token_1), 100009000);
+		assert_eq!(balance(pool_account, token_2), 10001);
+		assert_eq!(pool_balance(pool_account, lp_token), 100);
+
+		assert_ok!(AssetConversion::drain_pool(RuntimeOrigin::root(), token_2, token_1, dest));
+		assert!(events().contains(&Event::<Test>::PoolDrained {
+			pool_id,
+			dest,
+			amount1: 100009000,
+			amount2: 10001,
+		}));
+
+		// Nothing is stranded in the pool and it can be created again.
+		assert_eq!(balance(pool_account, token_1), 0);
+		assert_eq!(balance(pool_account, token_2), 0);
+		assert_eq!(balance(dest, token_1), 100009000);
+		assert_eq!(balance(dest, token_2), 10001);
+		assert_eq!(<PoolAssets as fungibles::Inspect<_>>::total_issuance(lp_token), 0);
+		assert!(pools().is_empty());
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+	});
+}
+
+#[test]
+fn drain_pool_reaps_the_pool_account_and_destroys_the_lp_token() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let dest = 5;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		let pool_id = (token_1, token_2);
+		let pool_account = AssetConversion::get_pool_account(&pool_id);
+
+		create_tokens(user, vec![token_2]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000000000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 200000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			1000000000,
+			100000,
+			1000000000,
+			100000,
+			user,
+		));
+		let lp = pool_balance(user, lp_token);
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			lp,
+			0,
+			0,
+			user,
+		));
+		assert!(System::account_exists(&pool_account));
+
+		assert_ok!(AssetConversion::drain_pool(RuntimeOrigin::root(), token_1, token_2, dest));
+		assert!(!System::account_exists(&pool_account));
+		assert!(!<PoolAssets as fungibles::Inspect<_>>::asset_exists(lp_token));
+
+		// The pool can be created again, with a fresh lp token, and provided with liquidity.
+		let new_lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ne!(new_lp_token, lp_token);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert!(System::account_exists(&pool_account));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			1000000000,
+			100000,
+			1000000000,
+			100000,
+			user,
+		));
+		assert_eq!(balance(pool_account, token_1), 1000000000);
+		assert_eq!(balance(pool_account, token_2), 100000);
+		assert!(pool_balance(user, new_lp_token) > 0);
+	});
+}
+
+#[test]
+fn redeemable_matches_remove_liquidity() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+
+		create_tokens(user, vec![token_2]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert_eq!(AssetConversion::redeemable(token_1, token_2, 1), None);
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000000000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 100000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			1000000000,
+			100000,
+			1000000000,
+			100000,
+			user,
+		));
+		LiquidityWithdrawalFee::set(&Permill::from_percent(10));
+
+		// The locked `MintMinLiquidity` backs a share of the reserves which is never redeemable.
+		let lp = pool_balance(user, lp_token);
+		assert_eq!(AssetConversion::redeemable(token_1, token_2, 0), None);
+		let (amount1, amount2) = AssetConversion::redeemable(token_1, token_2, lp).unwrap();
+		assert_eq!(AssetConversion::redeemable(token_2, token_1, lp), Some((amount2, amount1)));
+
+		let (before1, before2) = (balance(user, token_1), balance(user, token_2));
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			lp,
+			0,
+			0,
+			user,
+		));
+		assert_eq!(balance(user, token_1) - before1, amount1);
+		assert_eq!(balance(user, token_2) - before2, amount2);
+	});
+}
+
 #[test]
 fn can_not_redeem_more_lp_tokens_than_were_minted() {
 	new_test_ext().execute_with(|| {
@@ -714,8 +962,35 @@ fn can_not_swap_in_pool_with_no_liquidity_added_yet() {
 				user,
 				false,
 			),
-			Error::<Test>::PoolNotFound
+			Error::<Test>::PoolNotInitialized
+		);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(token_2, token_1, 10, true),
+			None
 		);
+
+		// Once seeded, the pool can be swapped against.
+		let ed = get_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			10000,
+			200,
+			1,
+			1,
+			user,
+		));
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_2, token_1],
+			10,
+			1,
+			user,
+			false,
+		));
 	});
 }
 
@@ -1354,6 +1629,113 @@ fn can_not_swap_same_asset() {
 	});
 }
 
+#[test]
+fn quote_path_chains_get_amount_out() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		let token_3 = NativeOrAssetId::Asset(3);
+		let token_4 = NativeOrAssetId::Asset(4);
+
+		create_tokens(user, vec![token_2, token_3, token_4]);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_2, token_3));
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_3, token_4));
+
+		let ed = get_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000 + ed));
+		for asset in [2, 3, 4] {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(user), asset, user, 10000));
+		}
+
+		let liquidity1 = 10000;
+		let liquidity2 = 200;
+		let liquidity3 = 2000;
+		for (asset1, asset2, amount1, amount2) in [
+			(token_1, token_2, liquidity1, liquidity2),
+			(token_2, token_3, liquidity2, liquidity3),
+			(token_3, token_4, liquidity3, liquidity3),
+		] {
+			assert_ok!(AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(user),
+				asset1,
+				asset2,
+				amount1,
+				amount2,
+				1,
+				1,
+				user,
+			));
+		}
+
+		let input_amount = 500;
+		let out2 =
+			AssetConversion::get_amount_out(&input_amount, &liquidity1, &liquidity2).unwrap();
+		let out3 = AssetConversion::get_amount_out(&out2, &liquidity2, &liquidity3).unwrap();
+		let out4 = AssetConversion::get_amount_out(&out3, &liquidity3, &liquidity3).unwrap();
+		assert_eq!(
+			AssetConversion::quote_path(vec![token_1, token_2, token_3, token_4], input_amount),
+			Some(vec![input_amount, out2, out3, out4])
+		);
+
+		// There is no pool for (token_1, token_3).
+		assert_eq!(
+			AssetConversion::quote_path(vec![token_1, token_3, token_4], input_amount),
+			None
+		);
+		// A step yielding nothing.
+		assert_eq!(AssetConversion::quote_path(vec![token_1, token_2], 1), None);
+		assert_eq!(AssetConversion::quote_path(vec![token_1], input_amount), None);
+	});
+}
+
+#[test]
+fn swap_math_rounds_in_favour_of_the_pool() {
+	new_test_ext().execute_with(|| {
+		// 997_000_000 / 1_997_000 = 499.25, rounded down.
+		assert_eq!(AssetConversion::get_amount_out(&1000, &1000, &1000).ok(), Some(499));
+		// 498_500_000 / 498_500 = 1000 exactly: nothing is added on top.
+		assert_eq!(AssetConversion::get_amount_in(&500, &997, &1000).ok(), Some(1000));
+		// 499_000_000 / 498_500 = 1001.003, rounded up.
+		assert_eq!(AssetConversion::get_amount_in(&500, &998, &1000).ok(), Some(1002));
+
+		for (reserve_in, reserve_out) in [(1000u128, 1000u128), (997, 1000), (10_000, 200), (7, 13)]
+		{
+			for amount_in in [1u128, 2, 3, 10, 99, 100, 997, 1000, 12_345] {
+				let amount_out =
+					AssetConversion::get_amount_out(&amount_in, &reserve_in, &reserve_out).unwrap();
+				if amount_out == 0 {
+					continue
+				}
+				// Getting the same amount out never costs more than was put in...
+				let needed =
+					AssetConversion::get_amount_in(&amount_out, &reserve_in, &reserve_out).unwrap();
+				assert!(needed <= amount_in);
+				// ...and swapping it straight back never returns more than was put in.
+				let returned = AssetConversion::get_amount_out(
+					&amount_out,
+					&(reserve_out - amount_out),
+					&(reserve_in + amount_in),
+				)
+				.unwrap();
+				assert!(returned <= amount_in);
+			}
+		}
+	});
+}
+
+#[test]
+fn get_amount_in_does_not_round_up_exact_division() {
+	new_test_ext().execute_with(|| {
+		// 997 * 997 * 1000 / ((1994 - 997) * 997) = 1000 exactly. The former `floor + 1`
+		// formula returned 1001 here.
+		assert_eq!(AssetConversion::get_amount_in(&997, &997, &1994).ok(), Some(1000));
+		// 997 * 998 * 1000 / ((1994 - 998) * 997) = 1002.008, rounded up.
+		assert_eq!(AssetConversion::get_amount_in(&998, &997, &1994).ok(), Some(1003));
+	});
+}
+
 #[test]
 fn validate_pool_id_sorting() {
 	new_test_ext().execute_with(|| {
@@ -24,7 +24,7 @@ use crate as pallet_bounties;
 
 use frame_support::{
 	assert_noop, assert_ok, parameter_types,
-	traits::{ConstU32, ConstU64, OnInitialize},
+	traits::{ConstBool, ConstU32, ConstU64, OnInitialize},
 	PalletId,
 };
 
@@ -99,6 +99,7 @@ impl pallet_balances::Config for Test {
 }
 parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const RejectionSlashFraction: Permill = Permill::from_percent(100);
 	pub static Burn: Permill = Permill::from_percent(50);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const TreasuryPalletId2: PalletId = PalletId(*b"py/trsr2");
@@ -114,15 +115,23 @@ impl pallet_treasury::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
 	type ProposalBond = ProposalBond;
+	type RejectionSlashFraction = RejectionSlashFraction;
 	type ProposalBondMinimum = ConstU64<1>;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = ConstU64<2>;
+	type SpendPeriodOffset = ConstU64<0>;
 	type Burn = Burn;
 	type BurnDestination = (); // Just gets burned.
 	type WeightInfo = ();
 	type SpendFunds = Bounties;
+	type OnSpend = ();
 	type MaxApprovals = ConstU32<100>;
 	type SpendOrigin = frame_system::EnsureRootWithSuccess<Self::AccountId, SpendLimit>;
+	type AllowLocalSpend = ConstBool<true>;
+	type MinimumSpend = ConstU64<0>;
+	type ApprovalExpiry = ();
+	type SlashExpiredApprovals = ();
+	type EmergencySpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
 }
 
 impl pallet_treasury::Config<Instance1> for Test {
@@ -133,15 +142,23 @@ impl pallet_treasury::Config<Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
 	type ProposalBond = ProposalBond;
+	type RejectionSlashFraction = RejectionSlashFraction;
 	type ProposalBondMinimum = ConstU64<1>;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = ConstU64<2>;
+	type SpendPeriodOffset = ConstU64<0>;
 	type Burn = Burn;
 	type BurnDestination = (); // Just gets burned.
 	type WeightInfo = ();
 	type SpendFunds = Bounties1;
+	type OnSpend = ();
 	type MaxApprovals = ConstU32<100>;
 	type SpendOrigin = frame_system::EnsureRootWithSuccess<Self::AccountId, SpendLimit1>;
+	type AllowLocalSpend = ConstBool<true>;
+	type MinimumSpend = ConstU64<0>;
+	type ApprovalExpiry = ();
+	type SlashExpiredApprovals = ();
+	type EmergencySpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
 }
 
 parameter_types! {
@@ -51,6 +51,8 @@ fn new_config_record<T: Config>() -> ConfigRecordOf<T> {
 		region_length: 3,
 		renewal_bump: Perbill::from_percent(10),
 		contribution_timeout: 5,
+		min_regular_price: None,
+		max_regular_price: None,
 	}
 }
 
@@ -656,6 +658,31 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn retry_revenue() -> Result<(), BenchmarkError> {
+		setup_and_start_sale::<T>()?;
+		let when = 5u32.into();
+		let amount = 10u32.into();
+
+		advance_to::<T>(2);
+
+		T::Currency::set_balance(
+			&Broker::<T>::account_id(),
+			T::Currency::minimum_balance().saturating_add(amount),
+		);
+		PendingRevenue::<T>::insert(when, amount);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), when);
+
+		assert!(PendingRevenue::<T>::get(when).is_none());
+		assert_last_event::<T>(Event::RevenueRetrySucceeded { when, amount }.into());
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn request_core_count(n: Linear<0, { MAX_CORE_COUNT.into() }>) -> Result<(), BenchmarkError> {
 		let admin_origin =
@@ -229,6 +229,9 @@ impl<T: Config> Pallet<T> {
 		ensure!(!pivot.is_void(), Error::<T>::VoidPivot);
 		ensure!(pivot != region_id.mask, Error::<T>::CompletePivot);
 
+		// The original Region is replaced by the two new ones.
+		Regions::<T>::remove(&region_id);
+
 		let one = RegionId { mask: pivot, ..region_id };
 		Regions::<T>::insert(&one, &region);
 		let other = RegionId { mask: region_id.mask ^ pivot, ..region_id };
@@ -424,6 +427,18 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_retry_revenue(when: Timeslice) -> Result<bool, DispatchError> {
+		let amount = PendingRevenue::<T>::get(when).ok_or(Error::<T>::NoPendingRevenue)?;
+		if Self::charge(&Self::account_id(), amount).is_ok() {
+			PendingRevenue::<T>::remove(when);
+			Self::deposit_event(Event::RevenueRetrySucceeded { when, amount });
+			Ok(true)
+		} else {
+			Self::deposit_event(Event::RevenueRetryFailed { when, amount });
+			Ok(false)
+		}
+	}
+
 	pub(crate) fn do_drop_renewal(core: CoreIndex, when: Timeslice) -> DispatchResult {
 		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
 		ensure!(status.last_committed_timeslice >= when, Error::<T>::StillValid);
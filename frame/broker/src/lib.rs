@@ -25,6 +25,7 @@ mod benchmarking;
 mod core_mask;
 mod coretime_interface;
 mod dispatchable_impls;
+pub mod migration;
 #[cfg(test)]
 mod mock;
 mod nonfungible_impl;
@@ -61,7 +62,11 @@ pub mod pallet {
 	use sp_runtime::traits::{Convert, ConvertBack};
 	use sp_std::vec::Vec;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -107,6 +112,11 @@ pub mod pallet {
 		/// Maximum number of system cores.
 		#[pallet::constant]
 		type MaxReservedCores: Get<u32>;
+
+		/// Maximum number of timeslices which may be caught up on in a single block. Any further
+		/// timeslices are left for subsequent blocks. Must be at least one.
+		#[pallet::constant]
+		type MaxTicksPerCall: Get<u32>;
 	}
 
 	/// The current configuration of this pallet.
@@ -161,6 +171,12 @@ pub mod pallet {
 	pub type InstaPoolHistory<T> =
 		StorageMap<_, Blake2_128Concat, Timeslice, InstaPoolHistoryRecordOf<T>>;
 
+	/// System InstaPool revenue for each Timeslice which could not be paid out when it was
+	/// processed and which is awaiting a retry.
+	#[pallet::storage]
+	pub type PendingRevenue<T> =
+		StorageMap<_, Blake2_128Concat, Timeslice, BalanceOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -408,6 +424,20 @@ pub mod pallet {
 			/// The core whose workload is no longer available to be renewed for `when`.
 			core: CoreIndex,
 		},
+		/// A deferred system InstaPool revenue payout has been made.
+		RevenueRetrySucceeded {
+			/// The timeslice whose system revenue has now been paid out.
+			when: Timeslice,
+			/// The amount which was paid out.
+			amount: BalanceOf<T>,
+		},
+		/// A deferred system InstaPool revenue payout was attempted but could not be made.
+		RevenueRetryFailed {
+			/// The timeslice whose system revenue remains pending.
+			when: Timeslice,
+			/// The amount which remains pending.
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -472,6 +502,8 @@ pub mod pallet {
 		AlreadyExpired,
 		/// The configuration could not be applied because it is invalid.
 		InvalidConfig,
+		/// There is no pending system revenue for the given timeslice.
+		NoPendingRevenue,
 	}
 
 	#[pallet::hooks]
@@ -479,6 +511,10 @@ pub mod pallet {
 		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
 			Self::do_tick()
 		}
+
+		fn integrity_test() {
+			assert!(T::MaxTicksPerCall::get() > 0, "`MaxTicksPerCall` must be at least one");
+		}
 	}
 
 	#[pallet::call(weight(<T as Config>::WeightInfo))]
@@ -780,5 +816,26 @@ pub mod pallet {
 			Self::do_request_core_count(core_count)?;
 			Ok(())
 		}
+
+		/// Retry paying out the system InstaPool revenue of a timeslice which could not be paid
+		/// out when it was processed.
+		///
+		/// - `origin`: Must be a Signed origin.
+		/// - `when`: The timeslice whose pending system revenue should be paid out.
+		#[pallet::call_index(19)]
+		pub fn retry_revenue(origin: OriginFor<T>, when: Timeslice) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let paid = Self::do_retry_revenue(when)?;
+			Ok(if paid { Pays::No } else { Pays::Yes }.into())
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// This runtime api allows to query the state of the Coretime sales.
+	pub trait BrokerApi<Balance> where Balance: codec::Codec {
+		/// Returns the price at which a Region of Bulk Coretime may currently be purchased, or
+		/// `None` if there is no ongoing sale.
+		fn current_price() -> Option<Balance>;
 	}
 }
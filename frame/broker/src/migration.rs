@@ -0,0 +1,100 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_arithmetic::Perbill;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use super::*;
+
+	/// The configuration record before the regular price bounds were added.
+	#[derive(Encode, Decode)]
+	pub struct OldConfigRecord<BlockNumber, RelayBlockNumber> {
+		pub advance_notice: RelayBlockNumber,
+		pub interlude_length: BlockNumber,
+		pub leadin_length: BlockNumber,
+		pub region_length: Timeslice,
+		pub ideal_bulk_proportion: Perbill,
+		pub limit_cores_offered: Option<CoreIndex>,
+		pub renewal_bump: Perbill,
+		pub contribution_timeout: Timeslice,
+	}
+
+	impl<BlockNumber, RelayBlockNumber> OldConfigRecord<BlockNumber, RelayBlockNumber> {
+		fn migrate_to_v1<Balance>(self) -> ConfigRecord<BlockNumber, RelayBlockNumber, Balance> {
+			ConfigRecord {
+				advance_notice: self.advance_notice,
+				interlude_length: self.interlude_length,
+				leadin_length: self.leadin_length,
+				region_length: self.region_length,
+				ideal_bulk_proportion: self.ideal_bulk_proportion,
+				limit_cores_offered: self.limit_cores_offered,
+				renewal_bump: self.renewal_bump,
+				contribution_timeout: self.contribution_timeout,
+				min_regular_price: None,
+				max_regular_price: None,
+			}
+		}
+	}
+
+	/// Migrate `Configuration` to the record with regular price bounds. Both bounds start unset.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version == 0 && current_version == 1 {
+				let _ = Configuration::<T>::translate::<
+					OldConfigRecord<BlockNumberFor<T>, RelayBlockNumberOf<T>>,
+					_,
+				>(|old| old.map(OldConfigRecord::migrate_to_v1));
+				current_version.put::<Pallet<T>>();
+				T::DbWeight::get().reads_writes(2, 2)
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "must upgrade linearly");
+			Ok(Configuration::<T>::exists().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(existed: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let existed: bool = Decode::decode(&mut existed.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must upgrade to version 1");
+			ensure!(
+				Configuration::<T>::get().is_some() == existed,
+				"the configuration should survive the migration"
+			);
+			Ok(())
+		}
+	}
+}
@@ -211,6 +211,7 @@ impl crate::Config for Test {
 	type TimeslicePeriod = ConstU32<2>;
 	type MaxLeasedCores = ConstU32<5>;
 	type MaxReservedCores = ConstU32<5>;
+	type MaxTicksPerCall = ConstU32<4>;
 	type Coretime = TestCoretimeProvider;
 	type ConvertBalance = Identity;
 	type WeightInfo = ();
@@ -253,6 +254,8 @@ pub fn new_config() -> ConfigRecordOf<Test> {
 		region_length: 3,
 		renewal_bump: Perbill::from_percent(10),
 		contribution_timeout: 5,
+		min_regular_price: None,
+		max_regular_price: None,
 	}
 }
 
@@ -303,6 +306,16 @@ impl TestExt {
 		self
 	}
 
+	pub fn min_regular_price(mut self, min_regular_price: Option<u64>) -> Self {
+		self.0.min_regular_price = min_regular_price;
+		self
+	}
+
+	pub fn max_regular_price(mut self, max_regular_price: Option<u64>) -> Self {
+		self.0.max_regular_price = max_regular_price;
+		self
+	}
+
 	pub fn endow(self, who: u64, amount: u64) -> Self {
 		assert_ok!(<<Test as Config>::Currency as Mutate<_>>::mint_into(&who, amount));
 		self
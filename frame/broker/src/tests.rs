@@ -18,13 +18,18 @@
 #![cfg(test)]
 
 use crate::{core_mask::*, mock::*, *};
+use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::nonfungible::{Inspect as NftInspect, Transfer},
+	traits::{
+		fungible::Mutate,
+		nonfungible::{Inspect as NftInspect, Transfer},
+		GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+	},
 	BoundedVec,
 };
 use frame_system::RawOrigin::Root;
-use sp_runtime::traits::Get;
+use sp_runtime::{traits::Get, Perbill};
 use CoreAssignment::*;
 use CoretimeTraceItem::*;
 use Finality::*;
@@ -332,6 +337,85 @@ fn renewal_works() {
 	});
 }
 
+#[test]
+fn regular_price_respects_floor() {
+	TestExt::new()
+		.ideal_bulk_proportion(Perbill::one())
+		.min_regular_price(Some(50))
+		.execute_with(|| {
+			assert_ok!(Broker::do_start_sales(100, 1));
+			assert_eq!(SaleInfo::<Test>::get().unwrap().price, 100);
+			// Nothing is ever sold, so without the floor the price would drop to zero.
+			for b in [6, 12, 18, 24] {
+				advance_to(b);
+				assert_eq!(SaleInfo::<Test>::get().unwrap().price, 50);
+			}
+		});
+}
+
+#[test]
+fn regular_price_respects_ceiling() {
+	TestExt::new()
+		.endow(1, 1000)
+		.ideal_bulk_proportion(Perbill::from_percent(50))
+		.max_regular_price(Some(150))
+		.execute_with(|| {
+			assert_ok!(Broker::do_start_sales(100, 2));
+			// Every sale sells out, so without the ceiling the price would double each time.
+			for (b, price) in [(2, 100), (8, 150), (14, 150)] {
+				advance_to(b);
+				assert_eq!(SaleInfo::<Test>::get().unwrap().price, price);
+				assert_ok!(Broker::do_purchase(1, u64::max_value()));
+				assert_ok!(Broker::do_purchase(1, u64::max_value()));
+			}
+			advance_to(18);
+			assert_eq!(SaleInfo::<Test>::get().unwrap().price, 150);
+		});
+}
+
+#[test]
+fn inverted_regular_price_bounds_are_invalid() {
+	TestExt::new().execute_with(|| {
+		let config = ConfigRecord {
+			min_regular_price: Some(100),
+			max_regular_price: Some(50),
+			..new_config()
+		};
+		assert_noop!(Broker::do_configure(config), Error::<Test>::InvalidConfig);
+	});
+}
+
+#[test]
+fn migration_to_v1_leaves_regular_price_unbounded() {
+	TestExt::new().execute_with(|| {
+		StorageVersion::new(0).put::<Broker>();
+		let old = migration::v1::OldConfigRecord::<u64, u32> {
+			advance_notice: 2,
+			interlude_length: 1,
+			leadin_length: 1,
+			region_length: 3,
+			ideal_bulk_proportion: Default::default(),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(10),
+			contribution_timeout: 5,
+		};
+		frame_support::storage::unhashed::put_raw(
+			&Configuration::<Test>::hashed_key(),
+			&old.encode(),
+		);
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Configuration::<Test>::get(), Some(new_config()));
+		assert_eq!(Broker::on_chain_storage_version(), 1);
+
+		// Running it again is a no-op.
+		let config = ConfigRecord { min_regular_price: Some(10), ..new_config() };
+		assert_ok!(Broker::do_configure(config.clone()));
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Configuration::<Test>::get(), Some(config));
+	});
+}
+
 #[test]
 fn instapool_payouts_work() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
@@ -353,6 +437,43 @@ fn instapool_payouts_work() {
 	});
 }
 
+#[test]
+fn failed_system_payout_is_deferred_and_can_be_retried() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		let item = ScheduleItem { assignment: Pool, mask: CoreMask::complete() };
+		assert_ok!(Broker::do_reserve(Schedule::truncate_from(vec![item])));
+		assert_ok!(Broker::do_start_sales(100, 3));
+		advance_to(2);
+		assert_ok!(Broker::do_purchase_credit(1, 20, 1));
+		advance_to(8);
+		assert_ok!(TestCoretimeProvider::spend_instantaneous(1, 10));
+		// The pot cannot cover the system payout when the revenue arrives.
+		<<Test as Config>::Currency as Mutate<_>>::set_balance(&Broker::account_id(), 0);
+		advance_to(11);
+		assert_eq!(revenue(), 0);
+		let pending: Vec<_> = PendingRevenue::<Test>::iter().collect();
+		assert_eq!(pending.len(), 1);
+		let (when, amount) = pending[0];
+		assert_eq!(amount, 10);
+
+		assert_ok!(Broker::retry_revenue(RuntimeOrigin::signed(2), when));
+		System::assert_last_event(Event::<Test>::RevenueRetryFailed { when, amount }.into());
+		assert_eq!(PendingRevenue::<Test>::get(when), Some(10));
+
+		<<Test as Config>::Currency as Mutate<_>>::set_balance(&Broker::account_id(), 10);
+		assert_ok!(Broker::retry_revenue(RuntimeOrigin::signed(2), when));
+		System::assert_last_event(Event::<Test>::RevenueRetrySucceeded { when, amount }.into());
+		assert_eq!(PendingRevenue::<Test>::get(when), None);
+		assert_eq!(revenue(), 10);
+		assert_eq!(pot(), 0);
+
+		assert_noop!(
+			Broker::retry_revenue(RuntimeOrigin::signed(2), when),
+			Error::<Test>::NoPendingRevenue
+		);
+	});
+}
+
 #[test]
 fn instapool_partial_core_payouts_work() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
@@ -463,6 +584,26 @@ fn initialize_with_leased_slots_works() {
 	});
 }
 
+#[test]
+fn current_price_decreases_through_leadin() {
+	TestExt::new().leadin_length(4).execute_with(|| {
+		assert_eq!(Broker::current_price(), None);
+		assert_ok!(Broker::do_start_sales(100, 1));
+		let sale = SaleInfo::<Test>::get().unwrap();
+		assert!(System::block_number() < sale.sale_start);
+		assert_eq!(Broker::current_price(), None);
+
+		let mut prices = vec![];
+		for b in sale.sale_start..=sale.sale_start + 4 {
+			advance_to(b);
+			prices.push(Broker::current_price().unwrap());
+		}
+		assert!(prices.windows(2).all(|w| w[0] > w[1]));
+		assert_eq!(prices.first(), Some(&200));
+		assert_eq!(prices.last(), Some(&100));
+	});
+}
+
 #[test]
 fn purchase_works() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
@@ -562,6 +703,30 @@ fn interlace_works() {
 	});
 }
 
+#[test]
+fn interlace_replaces_original_region() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let record = Regions::<Test>::get(&region).unwrap();
+		let (region1, region2) =
+			Broker::do_interlace(region, None, CoreMask::from_chunk(0, 30)).unwrap();
+		assert_eq!(region1.mask | region2.mask, region.mask);
+		assert!((region1.mask & region2.mask).is_void());
+		assert_eq!(Regions::<Test>::get(&region1), Some(record.clone()));
+		assert_eq!(Regions::<Test>::get(&region2), Some(record));
+
+		// The whole Region can no longer be used alongside its parts.
+		assert!(Regions::<Test>::get(&region).is_none());
+		assert_noop!(Broker::do_assign(region, None, 1000, Final), Error::<Test>::UnknownRegion);
+		assert_noop!(
+			Broker::do_interlace(region, None, CoreMask::from_chunk(0, 30)),
+			Error::<Test>::UnknownRegion
+		);
+	});
+}
+
 #[test]
 fn interlace_then_partition_works() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
@@ -894,3 +1059,139 @@ fn config_works() {
 		assert_noop!(Broker::configure(Root.into(), cfg), Error::<Test>::InvalidConfig);
 	});
 }
+
+#[test]
+fn sale_initialized_emitted_once_per_rotation() {
+	TestExt::new().execute_with(|| {
+		advance_to(1);
+		assert_ok!(Broker::do_start_sales(100, 1));
+		let sales = || {
+			System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					RuntimeEvent::Broker(e @ Event::SaleInitialized { .. }) => Some(e),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(sales().len(), 1);
+
+		// The sale rotates once the Regions it sold begin.
+		advance_to(5);
+		assert_eq!(sales().len(), 1);
+		advance_to(6);
+		assert_eq!(
+			sales().last(),
+			Some(&Event::SaleInitialized {
+				sale_start: 7,
+				leadin_length: 1,
+				start_price: 200,
+				regular_price: 100,
+				region_begin: 7,
+				region_end: 10,
+				ideal_cores_sold: 0,
+				cores_offered: 1,
+			})
+		);
+		assert_eq!(sales().len(), 2);
+
+		advance_to(11);
+		assert_eq!(sales().len(), 2);
+		advance_to(12);
+		assert_eq!(sales().len(), 3);
+		assert!(matches!(
+			sales().last(),
+			Some(Event::SaleInitialized { region_begin: 10, region_end: 13, .. })
+		));
+	});
+}
+
+#[test]
+fn core_assigned_breakdown_covers_whole_core() {
+	TestExt::new().execute_with(|| {
+		let schedule = vec![
+			ScheduleItem { assignment: Task(1000), mask: CoreMask::from_chunk(0, 20) },
+			ScheduleItem { assignment: Pool, mask: CoreMask::from_chunk(20, 40) },
+		];
+		assert_ok!(Broker::do_reserve(Schedule::truncate_from(schedule)));
+		advance_to(1);
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(6);
+		let assignments = System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::Broker(Event::CoreAssigned { core: 0, assignment, .. }) =>
+					Some(assignment),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert!(!assignments.is_empty());
+		for assignment in assignments {
+			assert_eq!(assignment, vec![(Idle, 28_800), (Pool, 14_400), (Task(1000), 14_400)]);
+			assert_eq!(assignment.iter().map(|i| i.1).sum::<PartsOf57600>(), 57_600);
+		}
+	});
+}
+
+#[test]
+fn lease_renewal_price_is_bumped_once_per_rotation() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_set_lease(1000, 6));
+		assert_ok!(Broker::do_start_sales(100, 2));
+		// The lease expires in the first sale and becomes renewable at the regular price.
+		let renewal = |when| AllowedRenewals::<Test>::get(AllowedRenewalId { core: 0, when });
+		assert_eq!(renewal(7).unwrap().price, 100);
+
+		advance_to(6);
+		assert_ok!(Broker::do_renew(1, 0));
+		assert_eq!(balance(1), 900);
+		assert_eq!(renewal(7), None);
+		assert_eq!(renewal(10).unwrap().price, 110);
+
+		advance_to(12);
+		assert_ok!(Broker::do_renew(1, 0));
+		assert_eq!(balance(1), 790);
+		assert_eq!(renewal(10), None);
+		assert_eq!(renewal(13).unwrap().price, 121);
+	});
+}
+
+#[test]
+fn tick_catches_up_on_large_timeslice_gap_in_bounded_steps() {
+	TestExt::new().execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		let last_timeslice = || Status::<Test>::get().unwrap().last_timeslice;
+		assert_eq!(last_timeslice(), 0);
+
+		// The chain stalls and resumes ten timeslices later.
+		System::set_block_number(20);
+		Broker::do_tick();
+		assert_eq!(last_timeslice(), 4);
+		Broker::do_tick();
+		assert_eq!(last_timeslice(), 8);
+		Broker::do_tick();
+		assert_eq!(last_timeslice(), 10);
+		Broker::do_tick();
+		assert_eq!(last_timeslice(), 10);
+	});
+}
+
+#[test]
+fn tick_commits_several_timeslices_in_one_block() {
+	TestExt::new().execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		let status = || Status::<Test>::get().unwrap();
+		let committed = status().last_committed_timeslice;
+		NotifyRevenueInfo::set(vec![]);
+
+		// The chain stalls and resumes ten timeslices later.
+		System::set_block_number(20);
+		Broker::do_tick();
+
+		// Several timeslices were committed...
+		assert_eq!(status().last_committed_timeslice, committed + 4);
+		// ...and the revenue of each timeslice caught up on was requested.
+		assert_eq!(status().last_timeslice, 4);
+		assert_eq!(NotifyRevenueInfo::get().len(), 4);
+	});
+}
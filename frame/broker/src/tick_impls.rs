@@ -51,33 +51,43 @@ impl<T: Config> Pallet<T> {
 			meter.consume(T::WeightInfo::process_revenue());
 		}
 
-		if let Some(commit_timeslice) = Self::next_timeslice_to_commit(&config, &status) {
-			status.last_committed_timeslice = commit_timeslice;
-			if let Some(sale) = SaleInfo::<T>::get() {
-				if commit_timeslice >= sale.region_begin {
-					// Sale can be rotated.
-					Self::rotate_sale(sale, &config, &status);
-					meter.consume(T::WeightInfo::rotate_sale(status.core_count.into()));
+		// Each iteration commits and advances at most one timeslice, exactly as a single tick
+		// would. A zero `MaxTicksPerCall` is treated as one so that the pallet keeps progressing.
+		let current_timeslice = Self::current_timeslice();
+		for _ in 0..T::MaxTicksPerCall::get().max(1) {
+			let maybe_commit_timeslice = Self::next_timeslice_to_commit(&config, &status);
+			if let Some(commit_timeslice) = maybe_commit_timeslice {
+				status.last_committed_timeslice = commit_timeslice;
+				if let Some(sale) = SaleInfo::<T>::get() {
+					if commit_timeslice >= sale.region_begin {
+						// Sale can be rotated.
+						Self::rotate_sale(sale, &config, &status);
+						meter.consume(T::WeightInfo::rotate_sale(status.core_count.into()));
+					}
 				}
-			}
 
-			Self::process_pool(commit_timeslice, &mut status);
-			meter.consume(T::WeightInfo::process_pool());
+				Self::process_pool(commit_timeslice, &mut status);
+				meter.consume(T::WeightInfo::process_pool());
 
-			let timeslice_period = T::TimeslicePeriod::get();
-			let rc_begin = RelayBlockNumberOf::<T>::from(commit_timeslice) * timeslice_period;
-			for core in 0..status.core_count {
-				Self::process_core_schedule(commit_timeslice, rc_begin, core);
-				meter.consume(T::WeightInfo::process_core_schedule());
+				let timeslice_period = T::TimeslicePeriod::get();
+				let rc_begin = RelayBlockNumberOf::<T>::from(commit_timeslice) * timeslice_period;
+				for core in 0..status.core_count {
+					Self::process_core_schedule(commit_timeslice, rc_begin, core);
+					meter.consume(T::WeightInfo::process_core_schedule());
+				}
 			}
-		}
 
-		let current_timeslice = Self::current_timeslice();
-		if status.last_timeslice < current_timeslice {
-			status.last_timeslice.saturating_inc();
-			let rc_block = T::TimeslicePeriod::get() * status.last_timeslice.into();
-			T::Coretime::request_revenue_info_at(rc_block);
-			meter.consume(T::WeightInfo::request_revenue_info_at());
+			let advance = status.last_timeslice < current_timeslice;
+			if advance {
+				status.last_timeslice.saturating_inc();
+				let rc_block = T::TimeslicePeriod::get() * status.last_timeslice.into();
+				T::Coretime::request_revenue_info_at(rc_block);
+				meter.consume(T::WeightInfo::request_revenue_info_at());
+			}
+
+			if maybe_commit_timeslice.is_none() && !advance {
+				break
+			}
 		}
 
 		Status::<T>::put(&status);
@@ -115,7 +125,11 @@ impl<T: Config> Pallet<T> {
 		let total_contrib = r.system_contributions.saturating_add(r.private_contributions);
 		let system_payout =
 			revenue.saturating_mul(r.system_contributions.into()) / total_contrib.into();
-		let _ = Self::charge(&Self::account_id(), system_payout);
+		if Self::charge(&Self::account_id(), system_payout).is_err() {
+			PendingRevenue::<T>::mutate(when, |pending| {
+				*pending = Some(pending.unwrap_or_default().saturating_add(system_payout));
+			});
+		}
 		revenue.saturating_reduce(system_payout);
 
 		if !revenue.is_zero() && r.private_contributions > 0 {
@@ -176,12 +190,15 @@ impl<T: Config> Pallet<T> {
 				// Sold less than the ideal - we fall back to the regular price.
 				Some(old_sale.price)
 			};
-			if let Some(purchase_price) = maybe_purchase_price {
+			let price = if let Some(purchase_price) = maybe_purchase_price {
 				T::PriceAdapter::adapt_price(sold.min(offered), ideal, offered)
 					.saturating_mul_int(purchase_price)
 			} else {
 				old_sale.price
-			}
+			};
+			// Keep the price within any bounds set by the configuration.
+			let price = config.min_regular_price.map_or(price, |min| price.max(min));
+			config.max_regular_price.map_or(price, |max| price.min(max))
 		};
 
 		// Set workload for the reserved (system, probably) workloads.
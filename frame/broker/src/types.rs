@@ -252,7 +252,7 @@ pub type LeasesRecordOf<T> = LeasesRecord<<T as Config>::MaxLeasedCores>;
 
 /// Configuration of this pallet.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct ConfigRecord<BlockNumber, RelayBlockNumber> {
+pub struct ConfigRecord<BlockNumber, RelayBlockNumber, Balance> {
 	/// The number of Relay-chain blocks in advance which scheduling should be fixed and the
 	/// `Coretime::assign` API used to inform the Relay-chain.
 	pub advance_notice: RelayBlockNumber,
@@ -272,12 +272,17 @@ pub struct ConfigRecord<BlockNumber, RelayBlockNumber> {
 	pub renewal_bump: Perbill,
 	/// The duration by which rewards for contributions to the InstaPool must be collected.
 	pub contribution_timeout: Timeslice,
+	/// If `Some`, the regular price will never be adapted below this value on sale rotation.
+	pub min_regular_price: Option<Balance>,
+	/// If `Some`, the regular price will never be adapted above this value on sale rotation.
+	pub max_regular_price: Option<Balance>,
 }
-pub type ConfigRecordOf<T> = ConfigRecord<BlockNumberFor<T>, RelayBlockNumberOf<T>>;
+pub type ConfigRecordOf<T> = ConfigRecord<BlockNumberFor<T>, RelayBlockNumberOf<T>, BalanceOf<T>>;
 
-impl<BlockNumber, RelayBlockNumber> ConfigRecord<BlockNumber, RelayBlockNumber>
+impl<BlockNumber, RelayBlockNumber, Balance> ConfigRecord<BlockNumber, RelayBlockNumber, Balance>
 where
 	BlockNumber: sp_arithmetic::traits::Zero,
+	Balance: PartialOrd,
 {
 	/// Check the config for basic validity constraints.
 	pub(crate) fn validate(&self) -> Result<(), ()> {
@@ -285,6 +290,12 @@ where
 			return Err(())
 		}
 
+		if let (Some(min), Some(max)) = (&self.min_regular_price, &self.max_regular_price) {
+			if min > max {
+				return Err(())
+			}
+		}
+
 		Ok(())
 	}
 }
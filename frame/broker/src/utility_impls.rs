@@ -66,6 +66,17 @@ impl<T: Config> Pallet<T> {
 		T::PriceAdapter::leadin_factor_at(through).saturating_mul_int(sale.price)
 	}
 
+	/// The price at which a Region of Bulk Coretime may be purchased in the current sale right
+	/// now, or `None` if there is no sale or it has not yet begun.
+	pub fn current_price() -> Option<BalanceOf<T>> {
+		let sale = SaleInfo::<T>::get()?;
+		let now = frame_system::Pallet::<T>::block_number();
+		if now < sale.sale_start {
+			return None
+		}
+		Some(Self::sale_price(&sale, now))
+	}
+
 	pub(crate) fn charge(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
 		let credit = T::Currency::withdraw(&who, amount, Exact, Expendable, Polite)?;
 		T::OnRevenue::on_unbalanced(credit);
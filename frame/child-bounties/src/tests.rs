@@ -24,7 +24,7 @@ use crate as pallet_child_bounties;
 
 use frame_support::{
 	assert_noop, assert_ok, parameter_types,
-	traits::{ConstU32, ConstU64, OnInitialize},
+	traits::{ConstBool, ConstU32, ConstU64, OnInitialize},
 	weights::Weight,
 	PalletId,
 };
@@ -102,6 +102,7 @@ impl pallet_balances::Config for Test {
 }
 parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const RejectionSlashFraction: Permill = Permill::from_percent(100);
 	pub const Burn: Permill = Permill::from_percent(50);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const SpendLimit: Balance = u64::MAX;
@@ -115,15 +116,23 @@ impl pallet_treasury::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
 	type ProposalBond = ProposalBond;
+	type RejectionSlashFraction = RejectionSlashFraction;
 	type ProposalBondMinimum = ConstU64<1>;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = ConstU64<2>;
+	type SpendPeriodOffset = ConstU64<0>;
 	type Burn = Burn;
 	type BurnDestination = ();
 	type WeightInfo = ();
 	type SpendFunds = Bounties;
+	type OnSpend = ();
 	type MaxApprovals = ConstU32<100>;
 	type SpendOrigin = frame_system::EnsureRootWithSuccess<Self::AccountId, SpendLimit>;
+	type AllowLocalSpend = ConstBool<true>;
+	type MinimumSpend = ConstU64<0>;
+	type ApprovalExpiry = ();
+	type SlashExpiredApprovals = ();
+	type EmergencySpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
 }
 parameter_types! {
 	// This will be 50% of the bounty fee.
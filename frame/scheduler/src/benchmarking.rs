@@ -224,6 +224,26 @@ benchmarks! {
 	verify {
 	}
 
+	// `schedule_retry` when the retry lands in an agenda with `s - 1` other tasks.
+	schedule_retry {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let now = BLOCK_NUMBER.into();
+		let period = BlockNumberFor::<T>::one();
+		fill_schedule::<T>(now + period, s - 1)?;
+		let task = make_task::<T>(false, true, false, None, 0);
+		let retry_config = RetryConfig { total_retries: 10, remaining: 10, period };
+	}: {
+		assert!(Scheduler::<T>::schedule_retry(
+			&mut WeightMeter::max_limit(),
+			now,
+			(now, 0),
+			task,
+			retry_config,
+		).is_ok());
+	} verify {
+		ensure!(Agenda::<T>::get(now + period).len() == s as usize, "didn't schedule retry");
+	}
+
 	schedule {
 		let s in 0 .. (T::MaxScheduledPerBlock::get() - 1);
 		let when = BLOCK_NUMBER.into();
@@ -306,5 +326,93 @@ benchmarks! {
 		);
 	}
 
+	cancel_named_batch {
+		let n in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, T::MaxScheduledPerBlock::get())?;
+		let ids: BoundedVec<_, _> = (0..n).map(u32_to_name).collect::<Vec<_>>().try_into().unwrap();
+	}: _(RawOrigin::Root, ids)
+	verify {
+		ensure!(
+			(0..n).all(|i| Lookup::<T>::get(u32_to_name(i)).is_none()),
+			"didn't remove all cancelled tasks from lookup"
+		);
+		ensure!(
+			(0..n).all(|i| Agenda::<T>::get(when).get(i as usize).map_or(true, |s| s.is_none())),
+			"didn't remove all cancelled tasks from schedule"
+		);
+	}
+
+	set_priority {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, when, s - 1, 255)
+	verify {
+		ensure!(
+			Agenda::<T>::get(when)[s as usize - 1].as_ref().map(|t| t.priority) == Some(255),
+			"didn't change priority"
+		);
+	}
+
+	reschedule_period {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, when, s - 1, (10u32.into(), 10))
+	verify {
+		ensure!(
+			Agenda::<T>::get(when)[s as usize - 1].as_ref().and_then(|t| t.maybe_periodic) ==
+				Some((10u32.into(), 9)),
+			"didn't change period"
+		);
+	}
+
+	compact_agenda {
+		let s in 2 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		// Cancelling the first task forces every other task to move, along with its retry
+		// configuration.
+		fill_schedule::<T>(when, s)?;
+		for index in 1..s {
+			Scheduler::<T>::do_set_retry(None, (when, index), 10, BlockNumberFor::<T>::one())?;
+		}
+		Scheduler::<T>::do_cancel_named(None, u32_to_name(0))?;
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), when)
+	verify {
+		ensure!(Agenda::<T>::get(when).len() == s as usize - 1, "didn't compact agenda");
+		ensure!(
+			Lookup::<T>::get(u32_to_name(s - 1)) == Some((when, s - 2)),
+			"didn't update lookup"
+		);
+		ensure!(Retries::<T>::get((when, s - 2)).is_some(), "didn't move retry config");
+		ensure!(Retries::<T>::get((when, s - 1)).is_none(), "didn't clear old retry config");
+	}
+
+	set_retry {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, when, s - 1, 10, BlockNumberFor::<T>::one())
+	verify {
+		ensure!(Retries::<T>::get((when, s - 1)).is_some(), "didn't set retry");
+	}
+
+	set_retry_named {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, u32_to_name(s - 1), 10, BlockNumberFor::<T>::one())
+	verify {
+		ensure!(Retries::<T>::get((when, s - 1)).is_some(), "didn't set retry");
+	}
+
 	impl_benchmark_test_suite!(Scheduler, crate::mock::new_test_ext(), crate::mock::Test);
 }
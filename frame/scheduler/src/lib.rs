@@ -117,6 +117,8 @@ pub use weights::WeightInfo;
 pub type PeriodicIndex = u32;
 /// The location of a scheduled task that can be used to remove it.
 pub type TaskAddress<BlockNumber> = (BlockNumber, u32);
+/// The number of times a failed task may be retried.
+pub type RetryCount = u8;
 
 pub type CallOrHashOf<T> =
 	MaybeHashed<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hash>;
@@ -147,6 +149,17 @@ pub struct Scheduled<Name, Call, BlockNumber, PalletsOrigin, AccountId> {
 	_phantom: PhantomData<AccountId>,
 }
 
+/// The retry configuration of a scheduled task.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct RetryConfig<Period> {
+	/// Initial amount of retries allowed.
+	total_retries: RetryCount,
+	/// Amount of retries left.
+	remaining: RetryCount,
+	/// Period of time between retry attempts.
+	period: Period,
+}
+
 use crate::{Scheduled as ScheduledV3, Scheduled as ScheduledV2};
 
 pub type ScheduledV2Of<T> = ScheduledV2<
@@ -280,6 +293,16 @@ pub mod pallet {
 	pub(crate) type Lookup<T: Config> =
 		StorageMap<_, Twox64Concat, TaskName, TaskAddress<BlockNumberFor<T>>>;
 
+	/// Retry configurations for items to be executed, indexed by task address.
+	#[pallet::storage]
+	pub type Retries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<BlockNumberFor<T>>,
+		RetryConfig<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -300,6 +323,41 @@ pub mod pallet {
 		PeriodicFailed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The given task can never be executed since it is overweight.
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// The given task failed and has been scheduled for another attempt.
+		Retried {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: Option<TaskName>,
+			remaining: RetryCount,
+		},
+		/// The given task failed and has no retries left.
+		RetriesExhausted { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// The given task failed and could not be retried since there was not enough weight left
+		/// to schedule the retry or the agenda of the retry block is full.
+		RetryFailed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// A batch of named tasks was canceled. `missing` ids were not scheduled.
+		BatchCancelled { cancelled: u32, missing: u32 },
+		/// The priority of a scheduled task was changed.
+		PriorityChanged { task: TaskAddress<BlockNumberFor<T>>, priority: schedule::Priority },
+		/// A conditional cancellation of a named task was attempted. `cancelled` is `false` if the
+		/// task was no longer pending.
+		CancelAttempted { id: TaskName, cancelled: bool },
+		/// The agenda for `when` was compacted, freeing `removed` empty slots.
+		AgendaCompacted { when: BlockNumberFor<T>, removed: u32 },
+		/// The period of a scheduled task was changed.
+		PeriodChanged {
+			task: TaskAddress<BlockNumberFor<T>>,
+			period: schedule::Period<BlockNumberFor<T>>,
+		},
+		/// A heartbeat postponed the named task `id` to `new_when`.
+		Heartbeat { id: TaskName, new_when: BlockNumberFor<T> },
+		/// The retry configuration of a scheduled task was set. Zero `retries` means it was
+		/// removed.
+		RetrySet {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: Option<TaskName>,
+			retries: RetryCount,
+			period: BlockNumberFor<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -314,6 +372,8 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// The agenda has no empty slots which can be removed.
+		AgendaAlreadyCompact,
 	}
 
 	#[pallet::hooks]
@@ -439,6 +499,170 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Cancel a batch of named scheduled tasks.
+		///
+		/// Ids which are not currently scheduled are skipped. The origin must be able to cancel
+		/// every task which is found, otherwise the whole batch fails.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_named_batch(ids.len() as u32))]
+		pub fn cancel_named_batch(
+			origin: OriginFor<T>,
+			ids: BoundedVec<TaskName, T::MaxScheduledPerBlock>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let (mut cancelled, mut missing) = (0u32, 0u32);
+			for id in ids {
+				match Self::do_cancel_named(Some(origin.caller().clone()), id) {
+					Ok(()) => cancelled.saturating_inc(),
+					Err(e) if e == Error::<T>::NotFound.into() => missing.saturating_inc(),
+					Err(e) => return Err(e),
+				}
+			}
+			Self::deposit_event(Event::BatchCancelled { cancelled, missing });
+			Ok(())
+		}
+
+		/// Change the priority of a scheduled task, keeping its call and dispatch time.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_priority(T::MaxScheduledPerBlock::get()))]
+		pub fn set_priority(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+			index: u32,
+			priority: schedule::Priority,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_priority(Some(origin.caller().clone()), (when, index), priority)
+		}
+
+		/// Cancel a named scheduled task if it is still pending.
+		///
+		/// Unlike `cancel_named`, this succeeds if the task has already been dispatched or
+		/// removed. Emits `CancelAttempted` reporting whether anything was cancelled.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_named(T::MaxScheduledPerBlock::get()))]
+		pub fn cancel_named_if_pending(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let cancelled = Self::do_cancel_named_if_pending(Some(origin.caller().clone()), id)?;
+			Self::deposit_event(Event::CancelAttempted { id, cancelled });
+			Ok(())
+		}
+
+		/// Remove empty slots left behind by cancelled tasks from the agenda of block `when`.
+		///
+		/// Named tasks are moved into the freed slots and their lookups updated. Anonymous tasks
+		/// are addressed by their index and therefore never moved.
+		///
+		/// May be called by any signed origin.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::compact_agenda(T::MaxScheduledPerBlock::get()))]
+		pub fn compact_agenda(origin: OriginFor<T>, when: BlockNumberFor<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_compact_agenda(when)
+		}
+
+		/// Change the `(period, count)` of a scheduled task, keeping its call and next dispatch
+		/// time.
+		///
+		/// `period` is interpreted as in `schedule`: `count` includes the next dispatch, so a
+		/// count of one or less turns the task into a one-off.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::reschedule_period(T::MaxScheduledPerBlock::get()))]
+		pub fn reschedule_period(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+			index: u32,
+			period: schedule::Period<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_reschedule_period(Some(origin.caller().clone()), (when, index), period)
+		}
+
+		/// Schedule a named task which runs at `when` unless it is cancelled or postponed first.
+		///
+		/// This is `schedule_named` for a one-off task with a hard deadline priority, meant to be
+		/// kept from firing by calling `heartbeat_named` (or `cancel_named`) before `when`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_unless_cancelled(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: BlockNumberFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_named(
+				id,
+				DispatchTime::At(when),
+				None,
+				schedule::HARD_DEADLINE,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Postpone the pending named task `id` by `extend_by` blocks.
+		///
+		/// Emits `Heartbeat` with the new dispatch block.
+		#[pallet::call_index(12)]
+		#[pallet::weight({
+			let s = T::MaxScheduledPerBlock::get();
+			<T as Config>::WeightInfo::cancel_named(s)
+				.saturating_add(<T as Config>::WeightInfo::schedule_named(s))
+		})]
+		pub fn heartbeat_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			extend_by: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_heartbeat_named(Some(origin.caller().clone()), id, extend_by)?;
+			Ok(())
+		}
+
+		/// Set a retry configuration for the task at `(when, index)`.
+		///
+		/// A dispatch of the task which fails will be attempted again `period` blocks later, up to
+		/// `retries` times. Each repetition of a periodic task gets a fresh set of retries. Setting
+		/// `retries` to zero removes the retry configuration.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry())]
+		pub fn set_retry(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+			index: u32,
+			retries: RetryCount,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_retry(Some(origin.caller().clone()), (when, index), retries, period)
+		}
+
+		/// Set a retry configuration for the named task `id`.
+		///
+		/// See `set_retry` for the semantics of `retries` and `period`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry_named())]
+		pub fn set_retry_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			retries: RetryCount,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let address = Lookup::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_retry(Some(origin.caller().clone()), address, retries, period)
+		}
 	}
 }
 
@@ -724,6 +948,28 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// The address at which the named task `id` is currently scheduled to be dispatched.
+	///
+	/// Returns `None` if no task with this name is scheduled.
+	pub fn next_dispatch_of(id: TaskName) -> Option<TaskAddress<BlockNumberFor<T>>> {
+		Lookup::<T>::get(id).filter(|(when, index)| {
+			Agenda::<T>::get(when).get(*index as usize).map_or(false, Option::is_some)
+		})
+	}
+
+	/// The total dispatch weight of the calls currently scheduled for block `when`.
+	///
+	/// Calls whose preimage is unavailable are not counted. This does not include the overhead
+	/// of servicing the agenda, so the result should be compared against a little less than
+	/// `T::MaximumWeight`.
+	pub fn scheduled_weight_at(when: BlockNumberFor<T>) -> Weight {
+		Agenda::<T>::get(when).iter().flatten().fold(Weight::zero(), |total, task| {
+			let weight = T::Preimages::peek(&task.call)
+				.map_or(Weight::zero(), |(call, _)| call.get_dispatch_info().weight);
+			total.saturating_add(weight)
+		})
+	}
+
 	fn resolve_time(
 		when: DispatchTime<BlockNumberFor<T>>,
 	) -> Result<BlockNumberFor<T>, DispatchError> {
@@ -794,6 +1040,55 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Remove the empty slots from the agenda of block `when`, keeping anonymous tasks at their
+	/// index.
+	pub fn do_compact_agenda(when: BlockNumberFor<T>) -> DispatchResult {
+		let agenda = Agenda::<T>::get(when);
+		let old_len = agenda.len();
+
+		// Take the named tasks out; anonymous tasks must keep their address.
+		let mut named = Vec::new();
+		let mut compacted: Vec<_> = agenda
+			.into_iter()
+			.enumerate()
+			.map(|(index, slot)| match slot {
+				Some(task) if task.maybe_id.is_some() => {
+					named.push((index as u32, task));
+					None
+				},
+				slot => slot,
+			})
+			.collect();
+
+		// Put them back into the lowest free slots, preserving their relative order.
+		let mut moves = Vec::new();
+		let mut named = named.into_iter();
+		for (index, slot) in compacted.iter_mut().enumerate().filter(|(_, s)| s.is_none()) {
+			let Some((old_index, task)) = named.next() else { break };
+			if old_index != index as u32 {
+				moves.push((task.maybe_id, old_index, index as u32));
+			}
+			*slot = Some(task);
+		}
+		let new_len = compacted.iter().rposition(Option::is_some).map_or(0, |i| i + 1);
+		ensure!(new_len < old_len, Error::<T>::AgendaAlreadyCompact);
+		compacted.truncate(new_len);
+
+		for (maybe_id, old_index, new_index) in moves {
+			if let Some(id) = maybe_id {
+				Lookup::<T>::insert(id, (when, new_index));
+			}
+			Self::move_retry_config((when, old_index), (when, new_index));
+		}
+		if compacted.is_empty() {
+			Agenda::<T>::remove(when);
+		} else {
+			Agenda::<T>::insert(when, BoundedVec::truncate_from(compacted));
+		}
+		Self::deposit_event(Event::AgendaCompacted { when, removed: (old_len - new_len) as u32 });
+		Ok(())
+	}
+
 	fn do_schedule(
 		when: DispatchTime<BlockNumberFor<T>>,
 		maybe_periodic: Option<schedule::Period<BlockNumberFor<T>>>,
@@ -853,6 +1148,7 @@ impl<T: Config> Pallet<T> {
 			if let Some(id) = s.maybe_id {
 				Lookup::<T>::remove(id);
 			}
+			Retries::<T>::remove((when, index));
 			Self::cleanup_agenda(when);
 			Self::deposit_event(Event::Canceled { when, index });
 			Ok(())
@@ -879,7 +1175,112 @@ impl<T: Config> Pallet<T> {
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
 
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		let new_address = Self::place_task(new_time, task).map_err(|x| x.0)?;
+		Self::move_retry_config((when, index), new_address);
+		Ok(new_address)
+	}
+
+	/// Change the priority of the task at `(when, index)` in place.
+	///
+	/// If `origin` is given it must have at least the privilege of the task's origin.
+	pub fn do_set_priority(
+		origin: Option<T::PalletsOrigin>,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		new_priority: schedule::Priority,
+	) -> DispatchResult {
+		Agenda::<T>::try_mutate(when, |agenda| -> DispatchResult {
+			let task = agenda
+				.get_mut(index as usize)
+				.and_then(Option::as_mut)
+				.ok_or(Error::<T>::NotFound)?;
+			if let Some(ref o) = origin {
+				if matches!(
+					T::OriginPrivilegeCmp::cmp_privilege(o, &task.origin),
+					Some(Ordering::Less) | None
+				) {
+					return Err(BadOrigin.into())
+				}
+			}
+			ensure!(task.priority != new_priority, Error::<T>::RescheduleNoChange);
+			task.priority = new_priority;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::PriorityChanged { task: (when, index), priority: new_priority });
+		Ok(())
+	}
+
+	/// Set the retry configuration of the task at `(when, index)`, removing it if `retries` or
+	/// `period` is zero.
+	///
+	/// If `origin` is given it must have at least the privilege of the task's origin.
+	pub fn do_set_retry(
+		origin: Option<T::PalletsOrigin>,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		retries: RetryCount,
+		period: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let agenda = Agenda::<T>::get(when);
+		let task = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		if let Some(ref o) = origin {
+			if matches!(
+				T::OriginPrivilegeCmp::cmp_privilege(o, &task.origin),
+				Some(Ordering::Less) | None
+			) {
+				return Err(BadOrigin.into())
+			}
+		}
+		let (retries, period) =
+			if retries == 0 || period.is_zero() { (0, Zero::zero()) } else { (retries, period) };
+		if retries == 0 {
+			Retries::<T>::remove((when, index));
+		} else {
+			Retries::<T>::insert(
+				(when, index),
+				RetryConfig { total_retries: retries, remaining: retries, period },
+			);
+		}
+		Self::deposit_event(Event::RetrySet {
+			task: (when, index),
+			id: task.maybe_id,
+			retries,
+			period,
+		});
+		Ok(())
+	}
+
+	/// Change the period of the task at `(when, index)` in place.
+	///
+	/// If `origin` is given it must have at least the privilege of the task's origin.
+	pub fn do_reschedule_period(
+		origin: Option<T::PalletsOrigin>,
+		(when, index): TaskAddress<BlockNumberFor<T>>,
+		new_period: schedule::Period<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		// sanitize the period the same way `do_schedule` does
+		let maybe_periodic =
+			Some(new_period).filter(|p| p.1 > 1 && !p.0.is_zero()).map(|(p, c)| (p, c - 1));
+		Agenda::<T>::try_mutate(when, |agenda| -> DispatchResult {
+			let task = agenda
+				.get_mut(index as usize)
+				.and_then(Option::as_mut)
+				.ok_or(Error::<T>::NotFound)?;
+			if let Some(ref o) = origin {
+				if matches!(
+					T::OriginPrivilegeCmp::cmp_privilege(o, &task.origin),
+					Some(Ordering::Less) | None
+				) {
+					return Err(BadOrigin.into())
+				}
+			}
+			ensure!(task.maybe_periodic != maybe_periodic, Error::<T>::RescheduleNoChange);
+			task.maybe_periodic = maybe_periodic;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::PeriodChanged { task: (when, index), period: new_period });
+		Ok(())
 	}
 
 	fn do_schedule_named(
@@ -942,6 +1343,7 @@ impl<T: Config> Pallet<T> {
 					}
 					Ok(())
 				})?;
+				Retries::<T>::remove((when, index));
 				Self::cleanup_agenda(when);
 				Self::deposit_event(Event::Canceled { when, index });
 				Ok(())
@@ -951,6 +1353,49 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Cancel the named task `id` if it is still pending.
+	///
+	/// Returns `Ok(false)` instead of `NotFound` if there is no such task.
+	pub fn do_cancel_named_if_pending(
+		origin: Option<T::PalletsOrigin>,
+		id: TaskName,
+	) -> Result<bool, DispatchError> {
+		match Self::do_cancel_named(origin, id) {
+			Ok(()) => Ok(true),
+			Err(e) if e == Error::<T>::NotFound.into() => Ok(false),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Push the dispatch of the pending named task `id` back by `extend_by` blocks.
+	///
+	/// If `origin` is given it must have at least the privilege of the task's origin. Returns
+	/// the block the task is now scheduled for.
+	pub fn do_heartbeat_named(
+		origin: Option<T::PalletsOrigin>,
+		id: TaskName,
+		extend_by: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let (when, index) = Lookup::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+		if let Some(ref o) = origin {
+			let agenda = Agenda::<T>::get(when);
+			let task = agenda
+				.get(index as usize)
+				.and_then(Option::as_ref)
+				.ok_or(Error::<T>::NotFound)?;
+			if matches!(
+				T::OriginPrivilegeCmp::cmp_privilege(o, &task.origin),
+				Some(Ordering::Less) | None
+			) {
+				return Err(BadOrigin.into())
+			}
+		}
+		let new_when = when.saturating_add(extend_by);
+		Self::do_reschedule_named(id, DispatchTime::At(new_when))?;
+		Self::deposit_event(Event::Heartbeat { id, new_when });
+		Ok(new_when)
+	}
+
 	fn do_reschedule_named(
 		id: TaskName,
 		new_time: DispatchTime<BlockNumberFor<T>>,
@@ -970,7 +1415,54 @@ impl<T: Config> Pallet<T> {
 		})?;
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		let new_address = Self::place_task(new_time, task).map_err(|x| x.0)?;
+		Self::move_retry_config((when, index), new_address);
+		Ok(new_address)
+	}
+
+	/// Move the retry configuration of the task at `from`, if any, to the task at `to`.
+	fn move_retry_config(from: TaskAddress<BlockNumberFor<T>>, to: TaskAddress<BlockNumberFor<T>>) {
+		if let Some(retry_config) = Retries::<T>::take(from) {
+			Retries::<T>::insert(to, retry_config);
+		}
+	}
+
+	/// Schedule another attempt of a task whose dispatch failed, according to its retry
+	/// configuration.
+	///
+	/// Returns the task back if it was not rescheduled, either because it has no retries left, the
+	/// `weight` counter cannot afford the retry or the agenda of the target block is full.
+	fn schedule_retry(
+		weight: &mut WeightMeter,
+		now: BlockNumberFor<T>,
+		failed: TaskAddress<BlockNumberFor<T>>,
+		task: ScheduledOf<T>,
+		retry_config: RetryConfig<BlockNumberFor<T>>,
+	) -> Result<(), ScheduledOf<T>> {
+		if retry_config.remaining == 0 {
+			Self::deposit_event(Event::RetriesExhausted { task: failed, id: task.maybe_id });
+			return Err(task)
+		}
+		if weight
+			.try_consume(T::WeightInfo::schedule_retry(T::MaxScheduledPerBlock::get()))
+			.is_err()
+		{
+			Self::deposit_event(Event::RetryFailed { task: failed, id: task.maybe_id });
+			return Err(task)
+		}
+		let id = task.maybe_id;
+		let wake = now.saturating_add(retry_config.period);
+		let new_address = match Self::place_task(wake, task) {
+			Ok(new_address) => new_address,
+			Err((_, task)) => {
+				Self::deposit_event(Event::RetryFailed { task: failed, id });
+				return Err(task)
+			},
+		};
+		let remaining = retry_config.remaining.saturating_sub(1);
+		Retries::<T>::insert(new_address, RetryConfig { remaining, ..retry_config });
+		Self::deposit_event(Event::Retried { task: failed, id, remaining });
+		Ok(())
 	}
 }
 
@@ -1081,7 +1573,9 @@ impl<T: Config> Pallet<T> {
 	/// This involves:
 	/// - removing and potentially replacing the `Lookup` entry for the task.
 	/// - realizing the task's call which can include a preimage lookup.
-	/// - Rescheduling the task for execution in a later agenda if periodic.
+	/// - Rescheduling the task for execution in a later agenda if periodic, or if its dispatch
+	///   failed and it has retries left. A retry does not count towards the task's periodic
+	///   repetitions.
 	fn service_task(
 		weight: &mut WeightMeter,
 		now: BlockNumberFor<T>,
@@ -1096,7 +1590,10 @@ impl<T: Config> Pallet<T> {
 
 		let (call, lookup_len) = match T::Preimages::peek(&task.call) {
 			Ok(c) => c,
-			Err(_) => return Err((Unavailable, Some(task))),
+			Err(_) => {
+				Retries::<T>::remove((when, agenda_index));
+				return Err((Unavailable, Some(task)))
+			},
 		};
 
 		let _ = weight.try_consume(T::WeightInfo::service_task(
@@ -1108,6 +1605,7 @@ impl<T: Config> Pallet<T> {
 		match Self::execute_dispatch(weight, task.origin.clone(), call) {
 			Err(Unavailable) => {
 				debug_assert!(false, "Checked to exist with `peek`");
+				Retries::<T>::remove((when, agenda_index));
 				Self::deposit_event(Event::CallUnavailable {
 					task: (when, agenda_index),
 					id: task.maybe_id,
@@ -1116,6 +1614,7 @@ impl<T: Config> Pallet<T> {
 			},
 			Err(Overweight) if is_first => {
 				T::Preimages::drop(&task.call);
+				Retries::<T>::remove((when, agenda_index));
 				Self::deposit_event(Event::PermanentlyOverweight {
 					task: (when, agenda_index),
 					id: task.maybe_id,
@@ -1124,11 +1623,27 @@ impl<T: Config> Pallet<T> {
 			},
 			Err(Overweight) => Err((Overweight, Some(task))),
 			Ok(result) => {
+				let failed = result.is_err();
 				Self::deposit_event(Event::Dispatched {
 					task: (when, agenda_index),
 					id: task.maybe_id,
 					result,
 				});
+				// The `service_task` benchmarks never dispatch, so they do not cover `Retries`.
+				let _ = weight.try_consume(T::DbWeight::get().reads_writes(1, 1));
+				let maybe_retry_config = Retries::<T>::take((when, agenda_index));
+				if let (true, Some(retry_config)) = (failed, maybe_retry_config) {
+					match Self::schedule_retry(
+						weight,
+						now,
+						(when, agenda_index),
+						task,
+						retry_config,
+					) {
+						Ok(()) => return Ok(()),
+						Err(t) => task = t,
+					}
+				}
 				if let &Some((period, count)) = &task.maybe_periodic {
 					if count > 1 {
 						task.maybe_periodic = Some((period, count - 1));
@@ -1137,7 +1652,18 @@ impl<T: Config> Pallet<T> {
 					}
 					let wake = now.saturating_add(period);
 					match Self::place_task(wake, task) {
-						Ok(_) => {},
+						Ok(new_address) =>
+							if let Some(retry_config) = maybe_retry_config {
+								// Each repetition of a periodic task gets a fresh set of retries.
+								let _ = weight.try_consume(T::DbWeight::get().writes(1));
+								Retries::<T>::insert(
+									new_address,
+									RetryConfig {
+										remaining: retry_config.total_retries,
+										..retry_config
+									},
+								);
+							},
 						Err((_, task)) => {
 							// TODO: Leave task in storage somewhere for it to be rescheduled
 							// manually.
@@ -1347,3 +1873,15 @@ fn map_err_to_v3_err<T: Config>(err: DispatchError) -> DispatchError {
 		err
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// This runtime api allows to query the dispatch time and weight of scheduled tasks.
+	pub trait SchedulerApi<BlockNumber> where BlockNumber: codec::Codec {
+		/// Returns the block number and agenda index at which the named task `id` will be
+		/// dispatched, or `None` if it is not scheduled.
+		fn next_dispatch_of(id: TaskName) -> Option<(BlockNumber, u32)>;
+
+		/// Returns the total dispatch weight of the calls scheduled for block `when`.
+		fn scheduled_weight_at(when: BlockNumber) -> Weight;
+	}
+}
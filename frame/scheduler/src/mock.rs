@@ -43,6 +43,8 @@ pub mod logger {
 
 	parameter_types! {
 		static Log: Vec<(OriginCaller, u32)> = Vec::new();
+		/// Number of upcoming `log_or_fail` calls which will fail.
+		pub static Failures: u32 = 0;
 	}
 	pub fn log() -> Vec<(OriginCaller, u32)> {
 		Log::get().clone()
@@ -89,6 +91,24 @@ pub mod logger {
 			});
 			Ok(())
 		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(*weight)]
+		pub fn log_or_fail(origin: OriginFor<T>, i: u32, weight: Weight) -> DispatchResult {
+			let fail = Failures::mutate(|f| {
+				let fail = *f > 0;
+				*f = f.saturating_sub(1);
+				fail
+			});
+			if fail {
+				return Err(DispatchError::Other("failing as requested"))
+			}
+			Self::deposit_event(Event::Logged(i, weight));
+			Log::mutate(|log| {
+				log.push((origin.caller().clone(), i));
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -197,6 +217,27 @@ impl WeightInfo for TestWeightInfo {
 	fn cancel_named(_s: u32) -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn cancel_named_batch(_n: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_priority(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn compact_agenda(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn reschedule_period(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn schedule_retry(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_retry() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_retry_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
 }
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
@@ -258,6 +258,43 @@ fn reschedule_named_works() {
 	});
 }
 
+#[test]
+fn reschedule_named_periodic_to_current_block_is_no_change() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			Some((3, 3)),
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+
+		run_to_block(3);
+		assert_noop!(
+			Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(4)),
+			Error::<Test>::RescheduleNoChange
+		);
+
+		// The next occurrence of the task is at block 7.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_noop!(
+			Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(7)),
+			Error::<Test>::RescheduleNoChange
+		);
+		assert_noop!(
+			Scheduler::do_reschedule_named([1u8; 32], DispatchTime::After(2)),
+			Error::<Test>::RescheduleNoChange
+		);
+
+		run_to_block(100);
+		assert_eq!(logger::log(), vec![(root(), 42u32); 3]);
+	});
+}
+
 #[test]
 fn reschedule_named_perodic_works() {
 	new_test_ext().execute_with(|| {
@@ -430,6 +467,30 @@ fn scheduler_respects_weight_limits() {
 	});
 }
 
+#[test]
+fn scheduled_weight_at_works() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		assert_eq!(Scheduler::scheduled_weight_at(4), Weight::zero());
+		for i in [42, 69] {
+			let call = RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 2 });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+		assert_eq!(Scheduler::scheduled_weight_at(4), max_weight / 2 * 2);
+		assert_eq!(Scheduler::scheduled_weight_at(5), Weight::zero());
+
+		// Cancelled tasks no longer count.
+		assert_ok!(Scheduler::do_cancel(None, (4, 1)));
+		assert_eq!(Scheduler::scheduled_weight_at(4), max_weight / 2);
+	});
+}
+
 /// Permanently overweight calls are not deleted but also not executed.
 #[test]
 fn scheduler_does_not_delete_permanently_overweight_call() {
@@ -609,6 +670,8 @@ fn scheduler_respects_priority_ordering_with_soft_deadlines() {
 fn on_initialize_weight_is_correct() {
 	new_test_ext().execute_with(|| {
 		let call_weight = Weight::from_parts(25, 0);
+		// Every dispatched task has its retry configuration taken.
+		let retries_weight = <Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
 
 		// Named
 		let call = RuntimeCall::Logger(LoggerCall::log {
@@ -668,6 +731,7 @@ fn on_initialize_weight_is_correct() {
 				TestWeightInfo::service_agenda_base(1) +
 				<TestWeightInfo as MarginalWeightInfo>::service_task(None, true, true) +
 				TestWeightInfo::execute_dispatch_unsigned() +
+				retries_weight +
 				call_weight + Weight::from_parts(4, 0)
 		);
 		assert_eq!(IncompleteSince::<Test>::get(), None);
@@ -680,9 +744,11 @@ fn on_initialize_weight_is_correct() {
 				TestWeightInfo::service_agenda_base(2) +
 				<TestWeightInfo as MarginalWeightInfo>::service_task(None, false, true) +
 				TestWeightInfo::execute_dispatch_unsigned() +
+				retries_weight +
 				call_weight + Weight::from_parts(3, 0) +
 				<TestWeightInfo as MarginalWeightInfo>::service_task(None, false, false) +
 				TestWeightInfo::execute_dispatch_unsigned() +
+				retries_weight +
 				call_weight + Weight::from_parts(2, 0)
 		);
 		assert_eq!(IncompleteSince::<Test>::get(), None);
@@ -695,6 +761,7 @@ fn on_initialize_weight_is_correct() {
 				TestWeightInfo::service_agenda_base(1) +
 				<TestWeightInfo as MarginalWeightInfo>::service_task(None, true, false) +
 				TestWeightInfo::execute_dispatch_unsigned() +
+				retries_weight +
 				call_weight + Weight::from_parts(1, 0)
 		);
 		assert_eq!(IncompleteSince::<Test>::get(), None);
@@ -1878,3 +1945,696 @@ fn reschedule_named_last_task_removes_agenda() {
 		assert!(Agenda::<Test>::get(when).len() == 0);
 	});
 }
+
+#[test]
+fn retry_scheduling_works() {
+	new_test_ext().execute_with(|| {
+		// The first two dispatches fail.
+		logger::Failures::set(2);
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 3, 2));
+		assert_eq!(
+			Retries::<Test>::get((4, 0)),
+			Some(RetryConfig { total_retries: 3, remaining: 3, period: 2 })
+		);
+
+		// First attempt fails and is retried 2 blocks later.
+		run_to_block(4);
+		assert!(logger::log().is_empty());
+		System::assert_last_event(Event::Retried { task: (4, 0), id: None, remaining: 2 }.into());
+		assert!(Retries::<Test>::get((4, 0)).is_none());
+		assert_eq!(
+			Retries::<Test>::get((6, 0)),
+			Some(RetryConfig { total_retries: 3, remaining: 2, period: 2 })
+		);
+
+		// Second attempt fails as well.
+		run_to_block(6);
+		assert!(logger::log().is_empty());
+		System::assert_last_event(Event::Retried { task: (6, 0), id: None, remaining: 1 }.into());
+
+		// Third attempt succeeds.
+		run_to_block(7);
+		assert!(logger::log().is_empty());
+		run_to_block(8);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retries_exhausted_works() {
+	new_test_ext().execute_with(|| {
+		logger::Failures::set(10);
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 1, 1));
+
+		run_to_block(4);
+		System::assert_last_event(Event::Retried { task: (4, 0), id: None, remaining: 0 }.into());
+		run_to_block(5);
+		System::assert_last_event(Event::RetriesExhausted { task: (5, 0), id: None }.into());
+
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+		assert_eq!(logger::Failures::get(), 8);
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retry_fails_when_the_retry_agenda_is_full() {
+	new_test_ext().execute_with(|| {
+		logger::Failures::set(1);
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 3, 2));
+
+		// Fill up the agenda the retry would land in.
+		let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+		for i in 0..max {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(6),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+
+		run_to_block(4);
+		System::assert_last_event(Event::RetryFailed { task: (4, 0), id: None }.into());
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+		assert_eq!(Agenda::<Test>::get(6).len() as u32, max);
+
+		run_to_block(6);
+		assert_eq!(logger::log().len() as u32, max);
+		assert!(logger::log().iter().all(|&(_, i)| i != 42));
+	});
+}
+
+#[test]
+fn retry_config_follows_cancel_and_reschedule() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		let address = Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		)
+		.unwrap();
+		assert_ok!(Scheduler::do_set_retry(None, address, 2, 2));
+
+		let address = Scheduler::do_reschedule(address, DispatchTime::At(6)).unwrap();
+		assert_eq!(address, (6, 0));
+		assert!(Retries::<Test>::get((4, 0)).is_none());
+		assert!(Retries::<Test>::get((6, 0)).is_some());
+
+		assert_ok!(Scheduler::do_cancel(None, address));
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retry_named_task_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		logger::Failures::set(1);
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_noop!(
+			Scheduler::set_retry_named(RuntimeOrigin::root(), [2u8; 32], 1, 3),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Scheduler::set_retry_named(RuntimeOrigin::root(), [1u8; 32], 1, 3));
+		System::assert_last_event(
+			Event::RetrySet { task: (4, 0), id: Some([1u8; 32]), retries: 1, period: 3 }.into(),
+		);
+
+		// The failed attempt is retried under the same name.
+		run_to_block(4);
+		System::assert_last_event(
+			Event::Retried { task: (4, 0), id: Some([1u8; 32]), remaining: 0 }.into(),
+		);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((7, 0)));
+
+		run_to_block(7);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(Lookup::<Test>::get([1u8; 32]).is_none());
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retry_config_is_removed_when_preimage_is_unavailable() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		// The preimage is never noted.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Bounded::Lookup { hash, len },
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 3, 1));
+
+		run_to_block(4);
+		assert!(logger::log().is_empty());
+		// The task stays in the agenda, but can never be retried.
+		assert!(Agenda::<Test>::get(4)[0].is_some());
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retry_config_is_removed_when_permanently_overweight() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail { i: 42, weight: max_weight });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 3, 1));
+
+		run_to_block(4);
+		System::assert_last_event(Event::PermanentlyOverweight { task: (4, 0), id: None }.into());
+		assert!(Agenda::<Test>::get(4)[0].is_some());
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retry_shifts_the_cadence_of_a_periodic_task() {
+	new_test_ext().execute_with(|| {
+		// Only the first dispatch fails.
+		logger::Failures::set(1);
+		let call = RuntimeCall::Logger(LoggerCall::log_or_fail {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((4, 3)),
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 2, 1));
+
+		// The failed dispatch is retried one block later instead of waiting for the next period.
+		run_to_block(4);
+		System::assert_last_event(Event::Retried { task: (4, 0), id: None, remaining: 1 }.into());
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+
+		// The following repetitions are counted from the retry, each with a fresh set of retries.
+		assert_eq!(
+			Retries::<Test>::get((9, 0)),
+			Some(RetryConfig { total_retries: 2, remaining: 2, period: 1 })
+		);
+		run_to_block(8);
+		assert_eq!(logger::log().len(), 1);
+		run_to_block(9);
+		assert_eq!(logger::log().len(), 2);
+		run_to_block(13);
+		assert_eq!(logger::log().len(), 3);
+
+		// A retry does not count towards the repetitions.
+		run_to_block(100);
+		assert_eq!(logger::log().len(), 3);
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn set_retry_checks_origin_and_zero_removes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_noop!(
+			Scheduler::set_retry(RuntimeOrigin::root(), 4, 1, 3, 1),
+			Error::<Test>::NotFound
+		);
+		assert_noop!(Scheduler::set_retry(RuntimeOrigin::signed(1), 4, 0, 3, 1), BadOrigin);
+
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 3, 1));
+		assert!(Retries::<Test>::get((4, 0)).is_some());
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), 4, 0, 0, 1));
+		System::assert_last_event(
+			Event::RetrySet { task: (4, 0), id: None, retries: 0, period: 0 }.into(),
+		);
+		assert_eq!(Retries::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn cancel_named_batch_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call = |i| {
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i,
+				weight: Weight::from_parts(10, 0),
+			}))
+			.unwrap()
+		};
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			call(1)
+		));
+		assert_ok!(Scheduler::do_schedule_named(
+			[2u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			call(2)
+		));
+		assert_ok!(Scheduler::do_schedule_named(
+			[3u8; 32],
+			DispatchTime::At(5),
+			None,
+			127,
+			root(),
+			call(3)
+		));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call(4)));
+
+		// `[9u8; 32]` was never scheduled and is skipped.
+		let ids = vec![[1u8; 32], [3u8; 32], [9u8; 32]].try_into().unwrap();
+		assert_ok!(Scheduler::cancel_named_batch(RuntimeOrigin::root(), ids));
+		System::assert_last_event(Event::BatchCancelled { cancelled: 2, missing: 1 }.into());
+
+		assert!(Lookup::<Test>::get([1u8; 32]).is_none());
+		assert!(Lookup::<Test>::get([3u8; 32]).is_none());
+		assert_eq!(Lookup::<Test>::get([2u8; 32]), Some((4, 1)));
+		assert_eq!(Agenda::<Test>::get(4).iter().filter(|s| s.is_some()).count(), 2);
+		assert!(!Agenda::<Test>::contains_key(5));
+
+		run_to_block(100);
+		assert_eq!(logger::log(), vec![(root(), 2u32), (root(), 4u32)]);
+	});
+}
+
+#[test]
+fn cancel_named_batch_checks_origin_per_task() {
+	new_test_ext().execute_with(|| {
+		let call = Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}))
+		.unwrap();
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			call
+		));
+
+		// A signed origin may not cancel a task scheduled by root.
+		let ids = vec![[9u8; 32], [1u8; 32]].try_into().unwrap();
+		assert_noop!(
+			Scheduler::cancel_named_batch(system::RawOrigin::Signed(1).into(), ids),
+			BadOrigin
+		);
+		assert!(Lookup::<Test>::get([1u8; 32]).is_some());
+	});
+}
+
+#[test]
+fn next_dispatch_of_tracks_reschedules() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_eq!(Scheduler::next_dispatch_of([1u8; 32]), None);
+
+		// Occupy the first slot at #6 so the rescheduled task lands at index 1.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(6),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_eq!(Scheduler::next_dispatch_of([1u8; 32]), Some((4, 0)));
+
+		assert_eq!(Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(6)).unwrap(), (6, 1));
+		assert_eq!(Scheduler::next_dispatch_of([1u8; 32]), Some((6, 1)));
+
+		assert_eq!(Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(8)).unwrap(), (8, 0));
+		assert_eq!(Scheduler::next_dispatch_of([1u8; 32]), Some((8, 0)));
+
+		run_to_block(8);
+		assert_eq!(Scheduler::next_dispatch_of([1u8; 32]), None);
+	});
+}
+
+#[test]
+fn reschedule_period_changes_cadence() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((3, 3)),
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+
+		// Unchanged period is rejected.
+		assert_noop!(
+			Scheduler::reschedule_period(RuntimeOrigin::root(), 4, 0, (3, 3)),
+			Error::<Test>::RescheduleNoChange
+		);
+		// Signed origins may not touch root tasks.
+		assert_noop!(
+			Scheduler::reschedule_period(system::RawOrigin::Signed(1).into(), 4, 0, (2, 4)),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::reschedule_period(RuntimeOrigin::root(), 4, 1, (2, 4)),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Scheduler::reschedule_period(RuntimeOrigin::root(), 4, 0, (2, 4)));
+		System::assert_last_event(Event::PeriodChanged { task: (4, 0), period: (2, 4) }.into());
+
+		// Dispatched at 4, 6, 8 and 10 instead of 4, 7 and 10.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		run_to_block(6);
+		assert_eq!(logger::log(), vec![(root(), 42u32); 2]);
+		run_to_block(7);
+		assert_eq!(logger::log().len(), 2);
+		run_to_block(8);
+		assert_eq!(logger::log().len(), 3);
+		run_to_block(20);
+		assert_eq!(logger::log().len(), 4);
+		assert!(Agenda::<Test>::iter().all(|(_, a)| a.iter().all(Option::is_none)));
+	});
+}
+
+#[test]
+fn heartbeats_keep_postponing_task() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_unless_cancelled(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			4,
+			Box::new(call),
+		));
+
+		// Signed origins may not postpone root tasks.
+		assert_noop!(
+			Scheduler::heartbeat_named(system::RawOrigin::Signed(1).into(), [1u8; 32], 3),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::heartbeat_named(RuntimeOrigin::root(), [2u8; 32], 3),
+			Error::<Test>::NotFound
+		);
+		assert_noop!(
+			Scheduler::heartbeat_named(RuntimeOrigin::root(), [1u8; 32], 0),
+			Error::<Test>::RescheduleNoChange
+		);
+
+		for n in [3, 6, 9] {
+			run_to_block(n);
+			assert_ok!(Scheduler::heartbeat_named(RuntimeOrigin::root(), [1u8; 32], 3));
+			System::assert_last_event(Event::Heartbeat { id: [1u8; 32], new_when: n + 4 }.into());
+		}
+		run_to_block(12);
+		assert!(logger::log().is_empty());
+		assert_eq!(Scheduler::next_dispatch_of([1u8; 32]), Some((13, 0)));
+	});
+}
+
+#[test]
+fn task_fires_once_heartbeats_stop() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_unless_cancelled(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			4,
+			Box::new(call),
+		));
+		run_to_block(3);
+		assert_ok!(Scheduler::heartbeat_named(RuntimeOrigin::root(), [1u8; 32], 3));
+
+		run_to_block(6);
+		assert!(logger::log().is_empty());
+		run_to_block(7);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_noop!(
+			Scheduler::heartbeat_named(RuntimeOrigin::root(), [1u8; 32], 3),
+			Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn set_priority_changes_execution_order() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		for i in [1u32, 2] {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+
+		// Unchanged priority is rejected.
+		assert_noop!(
+			Scheduler::set_priority(RuntimeOrigin::root(), 4, 1, 127),
+			Error::<Test>::RescheduleNoChange
+		);
+		// Signed origins may not touch root tasks.
+		assert_noop!(
+			Scheduler::set_priority(system::RawOrigin::Signed(1).into(), 4, 1, 0),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::set_priority(RuntimeOrigin::root(), 4, 2, 0),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Scheduler::set_priority(RuntimeOrigin::root(), 4, 1, 0));
+		System::assert_last_event(Event::PriorityChanged { task: (4, 1), priority: 0 }.into());
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 2u32), (root(), 1u32)]);
+	});
+}
+
+#[test]
+fn cancel_named_if_pending_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+
+		assert_ok!(Scheduler::cancel_named_if_pending(RuntimeOrigin::root(), [1u8; 32]));
+		System::assert_last_event(Event::CancelAttempted { id: [1u8; 32], cancelled: true }.into());
+		assert!(Lookup::<Test>::get([1u8; 32]).is_none());
+
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn cancel_named_if_pending_is_noop_after_dispatch() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+
+		assert_noop!(
+			Scheduler::cancel_named(RuntimeOrigin::root(), [1u8; 32]),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Scheduler::cancel_named_if_pending(RuntimeOrigin::root(), [1u8; 32]));
+		System::assert_last_event(
+			Event::CancelAttempted { id: [1u8; 32], cancelled: false }.into(),
+		);
+	});
+}
+
+#[test]
+fn compact_agenda_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call = |i| {
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i,
+				weight: Weight::from_parts(10, 0),
+			}))
+			.unwrap()
+		};
+		for i in 1..=3u8 {
+			assert_ok!(Scheduler::do_schedule_named(
+				[i; 32],
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				call(i as u32),
+			));
+		}
+		let anon =
+			Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call(10)).unwrap();
+		assert_ok!(Scheduler::do_schedule_named(
+			[4u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			call(4),
+		));
+
+		// Nothing to compact yet.
+		assert_noop!(
+			Scheduler::compact_agenda(RuntimeOrigin::signed(1), 4),
+			Error::<Test>::AgendaAlreadyCompact
+		);
+
+		assert_ok!(Scheduler::do_cancel_named(None, [2u8; 32]));
+		assert_ok!(Scheduler::do_cancel_named(None, [3u8; 32]));
+		assert_eq!(Agenda::<Test>::get(4).len(), 5);
+
+		assert_ok!(Scheduler::compact_agenda(RuntimeOrigin::signed(1), 4));
+		System::assert_last_event(Event::AgendaCompacted { when: 4, removed: 1 }.into());
+
+		// The anonymous task keeps its address; the named ones were moved to the front.
+		assert_eq!(anon, (4, 3));
+		let agenda = Agenda::<Test>::get(4);
+		assert_eq!(agenda.len(), 4);
+		assert!(agenda[2].is_none());
+		assert!(agenda[3].as_ref().map_or(false, |t| t.maybe_id.is_none()));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((4, 0)));
+		assert_eq!(Lookup::<Test>::get([4u8; 32]), Some((4, 1)));
+		assert_eq!(Scheduler::next_dispatch_of([4u8; 32]), Some((4, 1)));
+
+		// Already compact.
+		assert_noop!(
+			Scheduler::compact_agenda(RuntimeOrigin::signed(1), 4),
+			Error::<Test>::AgendaAlreadyCompact
+		);
+
+		// Named tasks can still be cancelled through their new address.
+		assert_ok!(Scheduler::do_cancel_named(None, [4u8; 32]));
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 10u32)]);
+	});
+}
@@ -64,6 +64,13 @@ pub trait WeightInfo {
 	fn cancel(s: u32, ) -> Weight;
 	fn schedule_named(s: u32, ) -> Weight;
 	fn cancel_named(s: u32, ) -> Weight;
+	fn cancel_named_batch(n: u32, ) -> Weight;
+	fn set_priority(s: u32, ) -> Weight;
+	fn compact_agenda(s: u32, ) -> Weight;
+	fn reschedule_period(s: u32, ) -> Weight;
+	fn schedule_retry(s: u32, ) -> Weight;
+	fn set_retry() -> Weight;
+	fn set_retry_named() -> Weight;
 }
 
 /// Weights for pallet_scheduler using the Substrate node and recommended hardware.
@@ -211,6 +218,120 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Lookup (r:512 w:512)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:512)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 512]`.
+	fn cancel_named_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102650 + n * (34 ±0)`
+		//  Estimated: `110487 + n * (2523 ±0)`
+		// Minimum execution time: 275_474_000 picoseconds.
+		Weight::from_parts(7_673_098_094, 110487)
+			// Standard Error: 3_270_597
+			.saturating_add(Weight::from_parts(216_561_041, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn set_priority(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `81 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 9_222_000 picoseconds.
+		Weight::from_parts(15_378_098, 110487)
+			// Standard Error: 5_258
+			.saturating_add(Weight::from_parts(475_926, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:511 w:512)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:511)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 512]`.
+	fn compact_agenda(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + s * (211 ±0)`
+		//  Estimated: `110487 + s * (2490 ±0)`
+		// Minimum execution time: 21_421_000 picoseconds.
+		Weight::from_parts(11_591_146, 110487)
+			// Standard Error: 77_813
+			.saturating_add(Weight::from_parts(7_629_944, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2490).saturating_mul(s.into()))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn reschedule_period(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `81 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 13_710_000 picoseconds.
+		Weight::from_parts(9_749_136, 110487)
+			// Standard Error: 6_340
+			.saturating_add(Weight::from_parts(477_488, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn schedule_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 19_358_000 picoseconds.
+		Weight::from_parts(18_614_681, 110487)
+			// Standard Error: 7_254
+			.saturating_add(Weight::from_parts(477_328, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90705`
+		//  Estimated: `110487`
+		// Minimum execution time: 123_084_000 picoseconds.
+		Weight::from_parts(179_672_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91747`
+		//  Estimated: `110487`
+		// Minimum execution time: 128_208_000 picoseconds.
+		Weight::from_parts(183_280_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -357,4 +478,118 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Lookup (r:512 w:512)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:512)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 512]`.
+	fn cancel_named_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `102650 + n * (34 ±0)`
+		//  Estimated: `110487 + n * (2523 ±0)`
+		// Minimum execution time: 275_474_000 picoseconds.
+		Weight::from_parts(7_673_098_094, 110487)
+			// Standard Error: 3_270_597
+			.saturating_add(Weight::from_parts(216_561_041, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn set_priority(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `81 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 9_222_000 picoseconds.
+		Weight::from_parts(15_378_098, 110487)
+			// Standard Error: 5_258
+			.saturating_add(Weight::from_parts(475_926, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:511 w:512)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:511)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 512]`.
+	fn compact_agenda(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + s * (211 ±0)`
+		//  Estimated: `110487 + s * (2490 ±0)`
+		// Minimum execution time: 21_421_000 picoseconds.
+		Weight::from_parts(11_591_146, 110487)
+			// Standard Error: 77_813
+			.saturating_add(Weight::from_parts(7_629_944, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2490).saturating_mul(s.into()))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn reschedule_period(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `81 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 13_710_000 picoseconds.
+		Weight::from_parts(9_749_136, 110487)
+			// Standard Error: 6_340
+			.saturating_add(Weight::from_parts(477_488, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn schedule_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 19_358_000 picoseconds.
+		Weight::from_parts(18_614_681, 110487)
+			// Standard Error: 7_254
+			.saturating_add(Weight::from_parts(477_328, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90705`
+		//  Estimated: `110487`
+		// Minimum execution time: 123_084_000 picoseconds.
+		Weight::from_parts(179_672_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91747`
+		//  Estimated: `110487`
+		// Minimum execution time: 128_208_000 picoseconds.
+		Weight::from_parts(183_280_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -29,7 +29,7 @@ use sp_storage::Storage;
 use frame_support::{
 	assert_noop, assert_ok, parameter_types,
 	storage::StoragePrefixedMap,
-	traits::{ConstU32, ConstU64, SortedMembers, StorageVersion},
+	traits::{ConstBool, ConstU32, ConstU64, SortedMembers, StorageVersion},
 	PalletId,
 };
 
@@ -120,6 +120,7 @@ impl ContainsLengthBound for TenToFourteen {
 }
 parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const RejectionSlashFraction: Permill = Permill::from_percent(100);
 	pub const Burn: Permill = Permill::from_percent(50);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const TreasuryPalletId2: PalletId = PalletId(*b"py/trsr2");
@@ -132,15 +133,23 @@ impl pallet_treasury::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
 	type ProposalBond = ProposalBond;
+	type RejectionSlashFraction = RejectionSlashFraction;
 	type ProposalBondMinimum = ConstU64<1>;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = ConstU64<2>;
+	type SpendPeriodOffset = ConstU64<0>;
 	type Burn = Burn;
 	type BurnDestination = (); // Just gets burned.
 	type WeightInfo = ();
 	type SpendFunds = ();
+	type OnSpend = ();
 	type MaxApprovals = ConstU32<100>;
 	type SpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
+	type AllowLocalSpend = ConstBool<true>;
+	type MinimumSpend = ConstU64<0>;
+	type ApprovalExpiry = ();
+	type SlashExpiredApprovals = ();
+	type EmergencySpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
 }
 
 impl pallet_treasury::Config<Instance1> for Test {
@@ -151,15 +160,23 @@ impl pallet_treasury::Config<Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
 	type ProposalBond = ProposalBond;
+	type RejectionSlashFraction = RejectionSlashFraction;
 	type ProposalBondMinimum = ConstU64<1>;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = ConstU64<2>;
+	type SpendPeriodOffset = ConstU64<0>;
 	type Burn = Burn;
 	type BurnDestination = (); // Just gets burned.
 	type WeightInfo = ();
 	type SpendFunds = ();
+	type OnSpend = ();
 	type MaxApprovals = ConstU32<100>;
 	type SpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
+	type AllowLocalSpend = ConstBool<true>;
+	type MinimumSpend = ConstU64<0>;
+	type ApprovalExpiry = ();
+	type SlashExpiredApprovals = ();
+	type EmergencySpendOrigin = frame_support::traits::NeverEnsureOrigin<u64>;
 }
 
 parameter_types! {
@@ -86,6 +86,35 @@ benchmarks_instance_pallet! {
 		}
 	}
 
+	// This benchmark is short-circuited if `EmergencySpendOrigin` cannot provide
+	// a successful origin, in which case `spend_immediate` is un-callable and can use weight=0.
+	spend_immediate {
+		let (_, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
+		let origin = T::EmergencySpendOrigin::try_successful_origin();
+		// Stay within what the origin is allowed to spend.
+		let value = origin
+			.clone()
+			.ok()
+			.and_then(|o| T::EmergencySpendOrigin::try_origin(o).ok())
+			.map_or(value, |max_amount| value.min(max_amount));
+		setup_pot_account::<T, I>();
+		// The spend touches the pot as well as the fresh beneficiary, so measure both even where
+		// the runtime whitelists the pot.
+		let pot_key = frame_system::Account::<T>::hashed_key_for(Treasury::<T, I>::account_id());
+		frame_benchmarking::benchmarking::remove_from_whitelist(pot_key);
+		let beneficiary = T::Lookup::lookup(beneficiary_lookup.clone()).unwrap();
+		let call = Call::<T, I>::spend_immediate { amount: value, beneficiary: beneficiary_lookup };
+	}: {
+		if let Ok(origin) = origin.clone() {
+			call.dispatch_bypass_filter(origin)?;
+		}
+	}
+	verify {
+		if origin.is_ok() {
+			assert_last_event::<T, I>(Event::EmergencySpend { amount: value, beneficiary }.into())
+		}
+	}
+
 	propose_spend {
 		let (caller, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
 		// Whitelist caller account from further DB operations.
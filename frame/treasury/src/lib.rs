@@ -48,7 +48,7 @@
 //!
 //! General spending/proposal protocol:
 //! - `propose_spend` - Make a spending proposal and stake the required deposit.
-//! - `reject_proposal` - Reject a proposal, slashing the deposit.
+//! - `reject_proposal` - Reject a proposal, slashing (part of) the deposit.
 //! - `approve_proposal` - Accept the proposal, returning the deposit.
 //! - `remove_approval` - Remove an approval, the deposit will no longer be returned.
 //!
@@ -59,6 +59,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -116,6 +117,15 @@ pub trait SpendFunds<T: Config<I>, I: 'static = ()> {
 	);
 }
 
+/// A trait to allow other pallets to observe funds awarded by the Treasury Pallet.
+///
+/// `on_spend` is called once for every approved proposal that is paid out during a spend period,
+/// after the funds have been allocated to the beneficiary.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnTreasurySpend<AccountId, Balance> {
+	fn on_spend(beneficiary: &AccountId, amount: Balance);
+}
+
 /// An index of a proposal. Just a `u32`.
 pub type ProposalIndex = u32;
 
@@ -139,7 +149,11 @@ pub mod pallet {
 	use frame_support::{dispatch_context::with_context, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
@@ -165,6 +179,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProposalBond: Get<Permill>;
 
+		/// Fraction of a rejected proposal's bond that is slashed. The remainder is returned to
+		/// the proposer. Set to 100% to slash the whole bond.
+		#[pallet::constant]
+		type RejectionSlashFraction: Get<Permill>;
+
 		/// Minimum amount of funds that should be placed in a deposit for making a proposal.
 		#[pallet::constant]
 		type ProposalBondMinimum: Get<BalanceOf<Self, I>>;
@@ -177,6 +196,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type SpendPeriod: Get<BlockNumberFor<Self>>;
 
+		/// Offset of the spend blocks: funds are spent at every block `n` for which
+		/// `n + SpendPeriodOffset` is a multiple of `SpendPeriod`. Use zero to spend at multiples
+		/// of `SpendPeriod`.
+		#[pallet::constant]
+		type SpendPeriodOffset: Get<BlockNumberFor<Self>>;
+
 		/// Percentage of spare funds (if any) that are burnt per spend period.
 		#[pallet::constant]
 		type Burn: Get<Permill>;
@@ -194,6 +219,9 @@ pub mod pallet {
 		/// Runtime hooks to external pallet using treasury to compute spend funds.
 		type SpendFunds: SpendFunds<Self, I>;
 
+		/// Handler called for each award made from the pot.
+		type OnSpend: OnTreasurySpend<Self::AccountId, BalanceOf<Self, I>>;
+
 		/// The maximum number of approvals that can wait in the spending queue.
 		///
 		/// NOTE: This parameter is also used within the Bounties Pallet extension if enabled.
@@ -204,6 +232,38 @@ pub mod pallet {
 		/// process. The `Success` value is the maximum amount that this origin is allowed to
 		/// spend at a time.
 		type SpendOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = BalanceOf<Self, I>>;
+
+		/// Whether the `spend` call is enabled. Runtimes migrating away from local spends can
+		/// disable it without removing the call.
+		#[pallet::constant]
+		type AllowLocalSpend: Get<bool>;
+
+		/// The minimum amount that can be approved through `spend`. Smaller requests are
+		/// rejected with `SpendTooSmall`.
+		///
+		/// Proposals are deliberately exempt: their bond already prices out spam.
+		/// `spend_immediate` is exempt too, as `EmergencySpendOrigin` is trusted to size its own
+		/// payouts.
+		#[pallet::constant]
+		type MinimumSpend: Get<BalanceOf<Self, I>>;
+
+		/// The number of blocks after which an approved proposal that still could not be paid
+		/// out is dropped from the queue. `None` keeps approvals queued until they are paid.
+		///
+		/// Approvals queued before `ApprovedAt` was introduced are given the block of the
+		/// [`migration::v1::MigrateToV1`] upgrade as their approval block.
+		#[pallet::constant]
+		type ApprovalExpiry: Get<Option<BlockNumberFor<Self>>>;
+
+		/// Whether the bond of an expired approval is slashed to `OnSlash` rather than returned
+		/// to the proposer.
+		#[pallet::constant]
+		type SlashExpiredApprovals: Get<bool>;
+
+		/// The origin required for paying out of the treasury immediately, bypassing the approval
+		/// queue and the spend period. The `Success` value is the maximum amount that this origin
+		/// is allowed to spend at a time.
+		type EmergencySpendOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = BalanceOf<Self, I>>;
 	}
 
 	/// Number of proposals that have been made.
@@ -233,6 +293,11 @@ pub mod pallet {
 	pub type Approvals<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<ProposalIndex, T::MaxApprovals>, ValueQuery>;
 
+	/// The block at which each queued proposal was approved.
+	#[pallet::storage]
+	pub type ApprovedAt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -261,8 +326,12 @@ pub mod pallet {
 		Spending { budget_remaining: BalanceOf<T, I> },
 		/// Some funds have been allocated.
 		Awarded { proposal_index: ProposalIndex, award: BalanceOf<T, I>, account: T::AccountId },
-		/// A proposal was rejected; funds were slashed.
-		Rejected { proposal_index: ProposalIndex, slashed: BalanceOf<T, I> },
+		/// A proposal was rejected; part of the bond was slashed and the rest refunded.
+		Rejected {
+			proposal_index: ProposalIndex,
+			slashed: BalanceOf<T, I>,
+			refunded: BalanceOf<T, I>,
+		},
 		/// Some of our funds have been burnt.
 		Burnt { burnt_funds: BalanceOf<T, I> },
 		/// Spending has finished; this is the amount that rolls over until next spend.
@@ -277,6 +346,11 @@ pub mod pallet {
 		},
 		/// The inactive funds of the pallet have been updated.
 		UpdatedInactive { reactivated: BalanceOf<T, I>, deactivated: BalanceOf<T, I> },
+		/// An approved proposal could not be paid out within `ApprovalExpiry` and was dropped;
+		/// its bond was slashed or refunded as configured by `SlashExpiredApprovals`.
+		ApprovalExpired { proposal_index: ProposalIndex, bond: BalanceOf<T, I>, slashed: bool },
+		/// Funds were paid out of the treasury immediately by the emergency spend origin.
+		EmergencySpend { amount: BalanceOf<T, I>, beneficiary: T::AccountId },
 	}
 
 	/// Error for the treasury pallet.
@@ -293,6 +367,10 @@ pub mod pallet {
 		InsufficientPermission,
 		/// Proposal has not been approved.
 		ProposalNotApproved,
+		/// Local spends are disabled by the runtime.
+		LocalSpendDisabled,
+		/// The amount to be spent is lower than `MinimumSpend`.
+		SpendTooSmall,
 	}
 
 	#[pallet::hooks]
@@ -313,7 +391,7 @@ pub mod pallet {
 			}
 
 			// Check to see if we should spend some funds!
-			if (n % T::SpendPeriod::get()).is_zero() {
+			if (n.saturating_add(T::SpendPeriodOffset::get()) % T::SpendPeriod::get()).is_zero() {
 				Self::spend_funds()
 			} else {
 				Weight::zero()
@@ -360,7 +438,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Reject a proposed spend. The original deposit will be slashed.
+		/// Reject a proposed spend. The `RejectionSlashFraction` of the original deposit will be
+		/// slashed and the remainder returned to the proposer.
 		///
 		/// May only be called from `T::RejectOrigin`.
 		///
@@ -380,13 +459,18 @@ pub mod pallet {
 
 			let proposal =
 				<Proposals<T, I>>::take(&proposal_id).ok_or(Error::<T, I>::InvalidIndex)?;
-			let value = proposal.bond;
-			let imbalance = T::Currency::slash_reserved(&proposal.proposer, value).0;
+			ApprovedAt::<T, I>::remove(proposal_id);
+			let slashed = T::RejectionSlashFraction::get() * proposal.bond;
+			let refunded = proposal.bond.saturating_sub(slashed);
+			let imbalance = T::Currency::slash_reserved(&proposal.proposer, slashed).0;
 			T::OnSlash::on_unbalanced(imbalance);
+			let err_amount = T::Currency::unreserve(&proposal.proposer, refunded);
+			debug_assert!(err_amount.is_zero());
 
 			Self::deposit_event(Event::<T, I>::Rejected {
 				proposal_index: proposal_id,
-				slashed: value,
+				slashed,
+				refunded,
 			});
 			Ok(())
 		}
@@ -413,6 +497,7 @@ pub mod pallet {
 			ensure!(<Proposals<T, I>>::contains_key(proposal_id), Error::<T, I>::InvalidIndex);
 			Approvals::<T, I>::try_append(proposal_id)
 				.map_err(|_| Error::<T, I>::TooManyApprovals)?;
+			ApprovedAt::<T, I>::insert(proposal_id, frame_system::Pallet::<T>::block_number());
 			Ok(())
 		}
 
@@ -424,6 +509,9 @@ pub mod pallet {
 		///
 		/// NOTE: For record-keeping purposes, the proposer is deemed to be equivalent to the
 		/// beneficiary.
+		///
+		/// Fails with `LocalSpendDisabled` if `AllowLocalSpend` is `false` and with
+		/// `SpendTooSmall` if `amount` is lower than `MinimumSpend`.
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::spend())]
 		pub fn spend(
@@ -432,6 +520,8 @@ pub mod pallet {
 			beneficiary: AccountIdLookupOf<T>,
 		) -> DispatchResult {
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			ensure!(T::AllowLocalSpend::get(), Error::<T, I>::LocalSpendDisabled);
+			ensure!(amount >= T::MinimumSpend::get(), Error::<T, I>::SpendTooSmall);
 			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
 
 			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
@@ -458,6 +548,7 @@ pub mod pallet {
 			let proposal_index = Self::proposal_count();
 			Approvals::<T, I>::try_append(proposal_index)
 				.map_err(|_| Error::<T, I>::TooManyApprovals)?;
+			ApprovedAt::<T, I>::insert(proposal_index, frame_system::Pallet::<T>::block_number());
 			let proposal = Proposal {
 				proposer: beneficiary.clone(),
 				value: amount,
@@ -495,6 +586,7 @@ pub mod pallet {
 			Approvals::<T, I>::try_mutate(|v| -> DispatchResult {
 				if let Some(index) = v.iter().position(|x| x == &proposal_id) {
 					v.remove(index);
+					ApprovedAt::<T, I>::remove(proposal_id);
 					Ok(())
 				} else {
 					Err(Error::<T, I>::ProposalNotApproved.into())
@@ -503,6 +595,33 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Pay `amount` out of the treasury to `beneficiary` right away, without going through
+		/// the approval queue or waiting for the next spend period.
+		///
+		/// - `origin`: Must be `EmergencySpendOrigin` with the `Success` value being at least
+		///   `amount`.
+		/// - `amount`: The amount to be transferred from the treasury to the `beneficiary`.
+		/// - `beneficiary`: The destination account for the transfer.
+		///
+		/// The transfer keeps the treasury account alive, so at most `pot` can be spent.
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::WeightInfo::spend_immediate(), DispatchClass::Operational))]
+		pub fn spend_immediate(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T, I>,
+			beneficiary: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let max_amount = T::EmergencySpendOrigin::ensure_origin(origin)?;
+			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			T::Currency::transfer(&Self::account_id(), &beneficiary, amount, KeepAlive)?;
+			T::OnSpend::on_spend(&beneficiary, amount);
+
+			Self::deposit_event(Event::EmergencySpend { amount, beneficiary });
+			Ok(())
+		}
 	}
 }
 
@@ -526,6 +645,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		r
 	}
 
+	/// Whether the approval of proposal `index` is older than `ApprovalExpiry` at block `now`.
+	///
+	/// Approvals without a recorded approval block never expire.
+	fn approval_expired(
+		index: ProposalIndex,
+		now: frame_system::pallet_prelude::BlockNumberFor<T>,
+	) -> bool {
+		let Some(expiry) = T::ApprovalExpiry::get() else { return false };
+		ApprovedAt::<T, I>::get(index)
+			.map_or(false, |approved_at| now >= approved_at.saturating_add(expiry))
+	}
+
 	/// Spend some money! returns number of approvals before spend.
 	pub fn spend_funds() -> Weight {
 		let mut total_weight = Weight::zero();
@@ -534,6 +665,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Self::deposit_event(Event::Spending { budget_remaining });
 		let account_id = Self::account_id();
 
+		let now = frame_system::Pallet::<T>::block_number();
 		let mut missed_any = false;
 		let mut imbalance = <PositiveImbalanceOf<T, I>>::zero();
 		let proposals_len = Approvals::<T, I>::mutate(|v| {
@@ -544,6 +676,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					if p.value <= budget_remaining {
 						budget_remaining -= p.value;
 						<Proposals<T, I>>::remove(index);
+						ApprovedAt::<T, I>::remove(index);
 
 						// return their deposit.
 						let err_amount = T::Currency::unreserve(&p.proposer, p.bond);
@@ -551,6 +684,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 						// provide the allocation.
 						imbalance.subsume(T::Currency::deposit_creating(&p.beneficiary, p.value));
+						T::OnSpend::on_spend(&p.beneficiary, p.value);
 
 						Self::deposit_event(Event::Awarded {
 							proposal_index: index,
@@ -558,11 +692,32 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							account: p.beneficiary,
 						});
 						false
+					} else if Self::approval_expired(index, now) {
+						<Proposals<T, I>>::remove(index);
+						ApprovedAt::<T, I>::remove(index);
+
+						let slashed = T::SlashExpiredApprovals::get();
+						if slashed {
+							let imbalance = T::Currency::slash_reserved(&p.proposer, p.bond).0;
+							T::OnSlash::on_unbalanced(imbalance);
+						} else {
+							let err_amount = T::Currency::unreserve(&p.proposer, p.bond);
+							debug_assert!(err_amount.is_zero());
+						}
+
+						Self::deposit_event(Event::ApprovalExpired {
+							proposal_index: index,
+							bond: p.bond,
+							slashed,
+						});
+						false
 					} else {
 						missed_any = true;
 						true
 					}
 				} else {
+					// The proposal was rejected after its approval.
+					ApprovedAt::<T, I>::remove(index);
 					false
 				}
 			});
@@ -607,6 +762,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		total_weight
 	}
 
+	/// The position of `proposal_id` in the approval queue, i.e. the number of approved proposals
+	/// that will be considered for funding before it, or `None` if it is not approved.
+	pub fn approval_position(proposal_id: ProposalIndex) -> Option<u32> {
+		Approvals::<T, I>::get()
+			.iter()
+			.position(|&i| i == proposal_id)
+			.map(|p| p as u32)
+	}
+
 	/// Return the amount of money in the pot.
 	// The existential deposit is not part of the pot so treasury account never gets deleted.
 	pub fn pot() -> BalanceOf<T, I> {
@@ -626,3 +790,12 @@ impl<T: Config<I>, I: 'static> OnUnbalanced<NegativeImbalanceOf<T, I>> for Palle
 		Self::deposit_event(Event::Deposit { value: numeric_amount });
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// This runtime api allows to query the state of the treasury spending queue.
+	pub trait TreasuryApi {
+		/// Returns the position of an approved proposal in the spending queue, or `None` if the
+		/// proposal has not been approved.
+		fn approval_position(proposal_id: ProposalIndex) -> Option<u32>;
+	}
+}
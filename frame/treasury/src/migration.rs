@@ -0,0 +1,76 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use super::*;
+
+	/// Record an approval block for every approval queued before `ApprovedAt` existed, so that
+	/// `ApprovalExpiry` counts from the upgrade rather than never applying to them.
+	pub struct MigrateToV1<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T, I>::current_storage_version();
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			if onchain_version == 0 && current_version == 1 {
+				let now = frame_system::Pallet::<T>::block_number();
+				let mut backfilled = 0u64;
+				let approvals = Approvals::<T, I>::get();
+				for index in approvals.iter() {
+					if !ApprovedAt::<T, I>::contains_key(index) {
+						ApprovedAt::<T, I>::insert(index, now);
+						backfilled.saturating_inc();
+					}
+				}
+				current_version.put::<Pallet<T, I>>();
+				T::DbWeight::get().reads_writes(
+					(approvals.len() as u64).saturating_add(3),
+					backfilled.saturating_add(1),
+				)
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(Pallet::<T, I>::on_chain_storage_version() == 0, "must upgrade linearly");
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T, I>::on_chain_storage_version() == 1, "must upgrade to version 1");
+			ensure!(
+				Approvals::<T, I>::get()
+					.iter()
+					.all(|index| ApprovedAt::<T, I>::contains_key(index)),
+				"every approval should have an approval block"
+			);
+			Ok(())
+		}
+	}
+}
@@ -27,7 +27,9 @@ use sp_runtime::{
 
 use frame_support::{
 	assert_err_ignore_postinfo, assert_noop, assert_ok, parameter_types,
-	traits::{ConstU32, ConstU64, OnInitialize},
+	traits::{
+		ConstU32, ConstU64, GetStorageVersion, OnInitialize, OnRuntimeUpgrade, StorageVersion,
+	},
 	PalletId,
 };
 
@@ -98,6 +100,13 @@ impl pallet_utility::Config for Test {
 
 parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub static RejectionSlashFraction: Permill = Permill::from_percent(100);
+	pub static SpendRecord: Vec<(u128, u64)> = vec![];
+	pub static AllowLocalSpend: bool = true;
+	pub static SpendPeriodOffset: u64 = 0;
+	pub static MinimumSpend: u64 = 0;
+	pub static ApprovalExpiry: Option<u64> = None;
+	pub static SlashExpiredApprovals: bool = false;
 	pub const Burn: Permill = Permill::from_percent(50);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 }
@@ -120,6 +129,13 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestSpendOrigin {
 	}
 }
 
+pub struct RecordSpend;
+impl OnTreasurySpend<u128, u64> for RecordSpend {
+	fn on_spend(beneficiary: &u128, amount: u64) {
+		SpendRecord::mutate(|r| r.push((*beneficiary, amount)));
+	}
+}
+
 impl Config for Test {
 	type PalletId = TreasuryPalletId;
 	type Currency = pallet_balances::Pallet<Test>;
@@ -128,15 +144,23 @@ impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSlash = ();
 	type ProposalBond = ProposalBond;
+	type RejectionSlashFraction = RejectionSlashFraction;
 	type ProposalBondMinimum = ConstU64<1>;
 	type ProposalBondMaximum = ();
 	type SpendPeriod = ConstU64<2>;
+	type SpendPeriodOffset = SpendPeriodOffset;
 	type Burn = Burn;
 	type BurnDestination = (); // Just gets burned.
 	type WeightInfo = ();
 	type SpendFunds = ();
+	type OnSpend = RecordSpend;
 	type MaxApprovals = ConstU32<100>;
 	type SpendOrigin = TestSpendOrigin;
+	type AllowLocalSpend = AllowLocalSpend;
+	type MinimumSpend = MinimumSpend;
+	type ApprovalExpiry = ApprovalExpiry;
+	type SlashExpiredApprovals = SlashExpiredApprovals;
+	type EmergencySpendOrigin = frame_system::EnsureRootWithSuccess<u128, ConstU64<50>>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -204,6 +228,216 @@ fn spend_origin_works() {
 	});
 }
 
+#[test]
+fn spend_respects_allow_local_spend() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		AllowLocalSpend::set(false);
+		assert_noop!(
+			Treasury::spend(RuntimeOrigin::signed(10), 5, 6),
+			Error::<Test>::LocalSpendDisabled
+		);
+
+		AllowLocalSpend::set(true);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), 5, 6));
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Balances::free_balance(6), 5);
+	});
+}
+
+#[test]
+fn spend_respects_minimum_spend() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		MinimumSpend::set(5);
+		assert_noop!(
+			Treasury::spend(RuntimeOrigin::signed(10), 4, 6),
+			Error::<Test>::SpendTooSmall
+		);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), 5, 6));
+		MinimumSpend::set(0);
+
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Balances::free_balance(6), 5);
+	});
+}
+
+#[test]
+fn minimum_spend_does_not_apply_to_proposals_or_spend_immediate() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		MinimumSpend::set(5);
+
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::propose_spend(RuntimeOrigin::signed(0), 4, 3)
+		});
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::approve_proposal(RuntimeOrigin::root(), 0)
+		});
+		assert_ok!(Treasury::spend_immediate(RuntimeOrigin::root(), 4, 6));
+		assert_eq!(Balances::free_balance(6), 4);
+
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Balances::free_balance(3), 4);
+		MinimumSpend::set(0);
+	});
+}
+
+#[test]
+fn unfundable_approval_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ApprovalExpiry::set(Some(3));
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::propose_spend(RuntimeOrigin::signed(0), 150, 3)
+		});
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::approve_proposal(RuntimeOrigin::root(), 0)
+		});
+		assert_eq!(Balances::reserved_balance(0), 7);
+
+		// Not fundable, but not expired yet either.
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Treasury::approvals().into_inner(), vec![0]);
+
+		System::set_block_number(4);
+		<Treasury as OnInitialize<u64>>::on_initialize(4);
+		System::assert_has_event(
+			Event::ApprovalExpired { proposal_index: 0, bond: 7, slashed: false }.into(),
+		);
+		assert!(Treasury::approvals().is_empty());
+		assert_eq!(Treasury::proposals(0), None);
+		assert_eq!(ApprovedAt::<Test>::get(0), None);
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(3), 0);
+		ApprovalExpiry::set(None);
+	});
+}
+
+#[test]
+fn expired_approval_bond_can_be_slashed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ApprovalExpiry::set(Some(3));
+		SlashExpiredApprovals::set(true);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::propose_spend(RuntimeOrigin::signed(0), 150, 3)
+		});
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::approve_proposal(RuntimeOrigin::root(), 0)
+		});
+		let free = Balances::free_balance(0);
+
+		System::set_block_number(4);
+		<Treasury as OnInitialize<u64>>::on_initialize(4);
+		System::assert_has_event(
+			Event::ApprovalExpired { proposal_index: 0, bond: 7, slashed: true }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), free);
+		ApprovalExpiry::set(None);
+		SlashExpiredApprovals::set(false);
+	});
+}
+
+#[test]
+fn rejecting_an_approved_proposal_clears_approved_at() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::propose_spend(RuntimeOrigin::signed(0), 150, 3)
+		});
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::approve_proposal(RuntimeOrigin::root(), 0)
+		});
+		assert!(ApprovedAt::<Test>::contains_key(0));
+
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::reject_proposal(RuntimeOrigin::root(), 0)
+		});
+		assert_eq!(ApprovedAt::<Test>::iter().count(), 0);
+
+		// An entry left behind by a rejection is cleaned up with its approval.
+		ApprovedAt::<Test>::insert(0, 1);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Treasury::approvals().len(), 0);
+		assert_eq!(ApprovedAt::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn migration_to_v1_backfills_approved_at() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ApprovalExpiry::set(Some(3));
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::propose_spend(RuntimeOrigin::signed(0), 150, 3)
+		});
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::approve_proposal(RuntimeOrigin::root(), 0)
+		});
+		// An approval queued before `ApprovedAt` existed.
+		ApprovedAt::<Test>::remove(0);
+		StorageVersion::new(0).put::<Treasury>();
+
+		System::set_block_number(10);
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(ApprovedAt::<Test>::get(0), Some(10));
+		assert_eq!(Treasury::on_chain_storage_version(), 1);
+
+		// It now expires counting from the upgrade.
+		System::set_block_number(12);
+		<Treasury as OnInitialize<u64>>::on_initialize(12);
+		assert_eq!(Treasury::approvals().into_inner(), vec![0]);
+		System::set_block_number(14);
+		<Treasury as OnInitialize<u64>>::on_initialize(14);
+		assert!(Treasury::approvals().is_empty());
+		ApprovalExpiry::set(None);
+	});
+}
+
+#[test]
+fn spend_immediate_pays_out_in_the_same_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_noop!(Treasury::spend_immediate(RuntimeOrigin::signed(10), 5, 6), BadOrigin);
+		assert_noop!(
+			Treasury::spend_immediate(RuntimeOrigin::root(), 51, 6),
+			Error::<Test>::InsufficientPermission
+		);
+
+		assert_ok!(Treasury::spend_immediate(RuntimeOrigin::root(), 50, 6));
+		System::assert_last_event(Event::EmergencySpend { amount: 50, beneficiary: 6 }.into());
+		assert_eq!(Balances::free_balance(6), 50);
+		assert_eq!(Treasury::pot(), 50);
+		assert_eq!(SpendRecord::get(), vec![(6, 50)]);
+		assert!(Treasury::approvals().is_empty());
+	});
+}
+
 #[test]
 fn minting_works() {
 	new_test_ext().execute_with(|| {
@@ -303,6 +537,31 @@ fn rejected_spend_proposal_ignored_on_spend_period() {
 	});
 }
 
+#[test]
+fn reject_proposal_slashes_fraction_of_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RejectionSlashFraction::set(Permill::from_percent(50));
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::propose_spend(RuntimeOrigin::signed(0), 100, 3)
+		});
+		assert_eq!(Balances::reserved_balance(0), 5);
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::reject_proposal(RuntimeOrigin::root(), 0)
+		});
+
+		System::assert_last_event(
+			Event::Rejected { proposal_index: 0, slashed: 2, refunded: 3 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 98);
+	});
+}
+
 #[test]
 fn reject_already_rejected_spend_proposal_fails() {
 	new_test_ext().execute_with(|| {
@@ -375,6 +634,34 @@ fn accept_already_rejected_spend_proposal_fails() {
 	});
 }
 
+#[test]
+fn spend_period_offset_shifts_spend_blocks() {
+	new_test_ext().execute_with(|| {
+		SpendPeriodOffset::set(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::propose_spend(RuntimeOrigin::signed(0), 100, 3)
+		});
+		assert_ok!({
+			#[allow(deprecated)]
+			Treasury::approve_proposal(RuntimeOrigin::root(), 0)
+		});
+
+		// Nothing is spent on a multiple of the spend period...
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(Balances::free_balance(3), 0);
+		assert_eq!(Treasury::pot(), 100);
+
+		// ...but one block earlier than that.
+		<Treasury as OnInitialize<u64>>::on_initialize(3);
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(Treasury::pot(), 0);
+		SpendPeriodOffset::set(0);
+	});
+}
+
 #[test]
 fn accepted_spend_proposal_enacted_on_spend_period() {
 	new_test_ext().execute_with(|| {
@@ -396,6 +683,26 @@ fn accepted_spend_proposal_enacted_on_spend_period() {
 	});
 }
 
+#[test]
+fn on_spend_hook_called_for_each_award() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!(Treasury::spend(RuntimeOrigin::root(), 30, 3));
+		assert_ok!(Treasury::spend(RuntimeOrigin::root(), 20, 4));
+		// Not enough funds in the pot, stays in the queue.
+		assert_ok!(Treasury::spend(RuntimeOrigin::root(), 60, 5));
+
+		<Treasury as OnInitialize<u64>>::on_initialize(1);
+		assert!(SpendRecord::get().is_empty());
+
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+		assert_eq!(SpendRecord::get(), vec![(3, 30), (4, 20)]);
+		assert_eq!(Balances::free_balance(3), 30);
+		assert_eq!(Balances::free_balance(4), 20);
+	});
+}
+
 #[test]
 fn pot_underflow_should_not_diminish() {
 	new_test_ext().execute_with(|| {
@@ -602,3 +909,33 @@ fn spending_in_batch_respects_max_total() {
 		);
 	})
 }
+
+#[test]
+fn approval_position_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		for _ in 0..4 {
+			assert_ok!({
+				#[allow(deprecated)]
+				Treasury::propose_spend(RuntimeOrigin::signed(0), 10, 3)
+			});
+		}
+		for proposal_id in [2, 0, 3] {
+			assert_ok!({
+				#[allow(deprecated)]
+				Treasury::approve_proposal(RuntimeOrigin::root(), proposal_id)
+			});
+		}
+
+		assert_eq!(Treasury::approval_position(2), Some(0));
+		assert_eq!(Treasury::approval_position(0), Some(1));
+		assert_eq!(Treasury::approval_position(3), Some(2));
+		assert_eq!(Treasury::approval_position(1), None);
+
+		assert_ok!(Treasury::remove_approval(RuntimeOrigin::root(), 2));
+		assert_eq!(Treasury::approval_position(0), Some(0));
+		assert_eq!(Treasury::approval_position(3), Some(1));
+		assert_eq!(Treasury::approval_position(2), None);
+	});
+}
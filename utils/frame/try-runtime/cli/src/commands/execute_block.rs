@@ -16,19 +16,28 @@
 // limitations under the License.
 
 use crate::{
-	build_executor, full_extensions, rpc_err_handler, state_machine_call_with_proof, LiveState,
-	SharedParams, State, LOG_TARGET,
+	build_executor, full_extensions, hash_of, parse, rpc_err_handler,
+	state_machine_call_with_proof, LiveState, SharedParams, State, LOG_TARGET,
 };
+use async_trait::async_trait;
 use parity_scale_codec::Encode;
+use remote_externalities::SnapshotConfig;
 use sc_executor::sp_wasm_interface::HostFunctions;
 use sp_rpc::{list::ListOrValue, number::NumberOrHex};
 use sp_runtime::{
 	generic::SignedBlock,
 	traits::{Block as BlockT, Header as HeaderT, NumberFor},
 };
-use std::{fmt::Debug, str::FromStr};
+use std::{
+	fmt::Debug,
+	path::{Path, PathBuf},
+	str::FromStr,
+};
 use substrate_rpc_client::{ws_client, ChainApi};
 
+/// The maximum number of block hashes fetched with a single request when executing a range.
+const MAX_HASHES_PER_REQUEST: u64 = 64;
+
 /// Configurations of the [`crate::Command::ExecuteBlock`].
 ///
 /// This will always call into `TryRuntime_execute_block`, which can optionally skip the state-root
@@ -60,6 +69,26 @@ pub struct ExecuteBlockCmd {
 	)]
 	pub block_ws_uri: Option<String>,
 
+	/// If set, keep executing blocks up to and including this one, carrying the state forward
+	/// from each block to the next.
+	///
+	/// Can be either a block number or a block hash. Execution stops at the first block that
+	/// fails.
+	///
+	/// When set, `--export-proof` is taken to be a folder, which is created if missing, and one
+	/// proof file named `{block_number}-{block_hash}` is written into it per executed block.
+	#[arg(long, value_parser = parse::number_or_hash)]
+	pub to_block: Option<String>,
+
+	/// A folder in which to cache the fetched state, keyed by block hash and by the `--pallet`
+	/// and `--child-tree` filters of the state.
+	///
+	/// Only used with a `live` state whose `--at` is given. If a snapshot of that block with the
+	/// same filters exists in the folder it is used instead of fetching the state from the node,
+	/// otherwise the fetched state is written to it.
+	#[arg(long)]
+	pub snapshot_cache: Option<PathBuf>,
+
 	/// The state type to use.
 	#[command(subcommand)]
 	pub state: State,
@@ -97,47 +126,264 @@ where
 	HostFns: HostFunctions,
 {
 	let executor = build_executor::<HostFns>(&shared);
-	let ext = command.state.into_ext::<Block, HostFns>(&shared, &executor, None, true).await?;
+	let mut ext = match (&command.snapshot_cache, &command.state) {
+		(Some(cache), State::Live(live @ LiveState { at: Some(at), .. })) => {
+			let snapshot_path = cached_snapshot_path(cache, live, at);
+			let at = hash_of::<Block>(at)?;
+			let maybe_ext = if snapshot_path.exists() {
+				let ext = State::Snap { snapshot_path: snapshot_path.clone() }
+					.into_ext::<Block, HostFns>(&shared, &executor, None, true)
+					.await?;
+				if ext.block_hash == at {
+					log::info!(target: LOG_TARGET, "using cached state from {:?}", snapshot_path);
+					Some(ext)
+				} else {
+					log::warn!(
+						target: LOG_TARGET,
+						"cached state {:?} is of block {:?}, re-fetching it",
+						snapshot_path,
+						ext.block_hash,
+					);
+					None
+				}
+			} else {
+				None
+			};
+			match maybe_ext {
+				Some(ext) => ext,
+				None => {
+					std::fs::create_dir_all(cache)
+						.map_err(|e| format!("failed to create snapshot cache: {:?}", e))?;
+					command
+						.state
+						.into_ext::<Block, HostFns>(
+							&shared,
+							&executor,
+							Some(SnapshotConfig::new(snapshot_path)),
+							true,
+						)
+						.await?
+				},
+			}
+		},
+		(maybe_cache, _) => {
+			if maybe_cache.is_some() {
+				log::warn!(
+					target: LOG_TARGET,
+					"--snapshot-cache is only used with a live state at a given block (--at)"
+				);
+			}
+			command.state.into_ext::<Block, HostFns>(&shared, &executor, None, true).await?
+		},
+	};
 
 	// get the block number associated with this block.
 	let block_ws_uri = command.block_ws_uri::<Block>();
 	let rpc = ws_client(&block_ws_uri).await?;
-	let next_hash = next_hash_of::<Block>(&rpc, ext.block_hash).await?;
 
-	log::info!(target: LOG_TARGET, "fetching next block: {:?} ", next_hash);
+	// the number of blocks to execute on top of `ext`.
+	let maybe_range = match command.to_block {
+		Some(ref to_block) => {
+			let from = number_of_hash::<Block>(&rpc, ext.block_hash).await?;
+			let to = number_of::<Block>(&rpc, to_block).await?;
+			if to <= from {
+				return Err(
+					format!("--to-block {} is not after the state's block {}", to, from).into()
+				)
+			}
+			Some(to - from)
+		},
+		None => None,
+	};
 
-	let block = ChainApi::<(), Block::Hash, Block::Header, SignedBlock<Block>>::block(
-		&rpc,
-		Some(next_hash),
-	)
-	.await
-	.map_err(rpc_err_handler)?
-	.expect("header exists, block should also exist; qed")
-	.block;
-
-	// A digest item gets added when the runtime is processing the block, so we need to pop
-	// the last one to be consistent with what a gossiped block would contain.
-	let (mut header, extrinsics) = block.deconstruct();
-	header.digest_mut().pop();
-	let block = Block::new(header, extrinsics);
-
-	// for now, hardcoded for the sake of simplicity. We might customize them one day.
-	let state_root_check = false;
-	let signature_check = false;
-	let payload = (block.clone(), state_root_check, signature_check, command.try_state).encode();
-
-	let _ = state_machine_call_with_proof::<Block, HostFns>(
-		&ext,
-		&executor,
-		"TryRuntime_execute_block",
-		&payload,
-		full_extensions(executor.clone()),
-		shared.export_proof,
-	)?;
+	if let (Some(_), Some(folder)) = (maybe_range, &shared.export_proof) {
+		std::fs::create_dir_all(folder)
+			.map_err(|e| format!("failed to create proof folder: {:?}", e))?;
+	}
+
+	let mut parent_number = number_of_hash::<Block>(&rpc, ext.block_hash).await?;
+	let mut remaining = maybe_range.unwrap_or(1);
+	while remaining > 0 {
+		let count = remaining.min(MAX_HASHES_PER_REQUEST);
+		for (next_hash, block) in next_blocks_of::<Block, _>(&rpc, parent_number, count).await? {
+			// A digest item gets added when the runtime is processing the block, so we need to
+			// pop the last one to be consistent with what a gossiped block would contain.
+			let (mut header, extrinsics) = block.deconstruct();
+			header.digest_mut().pop();
+			let number = *header.number();
+			let block = Block::new(header, extrinsics);
+
+			// for now, hardcoded for the sake of simplicity. We might customize them one day.
+			let state_root_check = false;
+			let signature_check = false;
+			let payload =
+				(block.clone(), state_root_check, signature_check, command.try_state.clone())
+					.encode();
+
+			// when executing a range, write one proof file per block.
+			let maybe_export_proof = match maybe_range {
+				Some(_) => shared
+					.export_proof
+					.as_ref()
+					.map(|path| path.as_path().join(&format!("{}-{:?}", number, next_hash))),
+				None => shared.export_proof.clone(),
+			};
+
+			let (mut changes, _) = state_machine_call_with_proof::<Block, HostFns>(
+				&ext,
+				&executor,
+				"TryRuntime_execute_block",
+				&payload,
+				full_extensions(executor.clone()),
+				maybe_export_proof,
+			)
+			.map_err(|why| {
+				log::error!(
+					target: LOG_TARGET,
+					"failed to execute block {:?} due to {:?}",
+					number,
+					why
+				);
+				why
+			})?;
+
+			if maybe_range.is_some() {
+				// carry the post-state of this block forward, so that the next one executes on
+				// top of it.
+				let storage_changes = changes
+					.drain_storage_changes(&ext.backend, ext.state_version)
+					.map_err(|e| format!("failed to drain storage changes: {:?}", e))?;
+				ext.backend.apply_transaction(
+					storage_changes.transaction_storage_root,
+					storage_changes.transaction,
+				);
+
+				log::info!(
+					target: LOG_TARGET,
+					"executed block {}, new storage root {:?}",
+					number,
+					ext.as_backend().root(),
+				);
+			}
+		}
+		parent_number += count;
+		remaining -= count;
+	}
 
 	Ok(())
 }
 
+/// The file in `cache` holding the state of `live` at block `at`.
+///
+/// The name includes a digest of the pallet and child-tree filters, so that states scraped with
+/// different filters never share a file.
+fn cached_snapshot_path(cache: &Path, live: &LiveState, at: &str) -> PathBuf {
+	let mut pallets = live.pallet.clone();
+	pallets.sort();
+	pallets.dedup();
+	let filters = sp_core::twox_64(&(pallets, live.child_tree).encode());
+	cache.join(format!("{}-{}.snap", at, hex::encode(filters)))
+}
+
+/// Resolve the block number of `block`, which is either a block number or a block hash.
+async fn number_of<Block: BlockT>(
+	rpc: &substrate_rpc_client::WsClient,
+	block: &str,
+) -> sc_cli::Result<u64>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	<Block::Hash as FromStr>::Err: Debug,
+	Block::Header: serde::de::DeserializeOwned,
+{
+	match block.parse::<u64>() {
+		Ok(number) => Ok(number),
+		Err(_) => number_of_hash::<Block>(rpc, hash_of::<Block>(block)?).await,
+	}
+}
+
+/// Fetch the block number of the block with the given `hash`.
+async fn number_of_hash<Block: BlockT>(
+	rpc: &substrate_rpc_client::WsClient,
+	hash: Block::Hash,
+) -> sc_cli::Result<u64>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Header: serde::de::DeserializeOwned,
+{
+	let number = ChainApi::<(), Block::Hash, Block::Header, ()>::header(rpc, Some(hash))
+		.await
+		.map_err(rpc_err_handler)
+		.and_then(|maybe_header| maybe_header.ok_or("header_not_found").map(|h| *h.number()))?;
+
+	number.try_into().map_err(|_| "failed to convert block number to number".into())
+}
+
+/// Abstraction over the RPC calls used to fetch the blocks to execute.
+#[async_trait]
+pub(crate) trait BlockProvider<Block: BlockT> {
+	/// The hashes of the canonical blocks with the given `numbers`, in the same order.
+	async fn hashes_of(&self, numbers: Vec<u64>) -> sc_cli::Result<Vec<Block::Hash>>;
+
+	/// The block with hash `hash`.
+	async fn block_of(&self, hash: Block::Hash) -> sc_cli::Result<Block>;
+}
+
+#[async_trait]
+impl<Block> BlockProvider<Block> for substrate_rpc_client::WsClient
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Header: serde::de::DeserializeOwned,
+{
+	async fn hashes_of(&self, numbers: Vec<u64>) -> sc_cli::Result<Vec<Block::Hash>> {
+		let numbers = numbers.into_iter().map(NumberOrHex::Number).collect::<Vec<_>>();
+		let hashes = match ChainApi::<(), Block::Hash, Block::Header, ()>::block_hash(
+			self,
+			Some(ListOrValue::List(numbers)),
+		)
+		.await
+		.map_err(rpc_err_handler)?
+		{
+			ListOrValue::List(hashes) => hashes,
+			ListOrValue::Value(_) => return Err("expected a list of block hashes".into()),
+		};
+
+		hashes
+			.into_iter()
+			.collect::<Option<Vec<_>>>()
+			.ok_or_else(|| "block_not_found".into())
+	}
+
+	async fn block_of(&self, hash: Block::Hash) -> sc_cli::Result<Block> {
+		ChainApi::<(), Block::Hash, Block::Header, SignedBlock<Block>>::block(self, Some(hash))
+			.await
+			.map_err(rpc_err_handler)?
+			.map(|signed| signed.block)
+			.ok_or_else(|| "block_not_found".into())
+	}
+}
+
+/// Fetch the `count` blocks following block number `number`, in order.
+///
+/// All hashes are requested with a single `chain_getBlockHash` call, after which the blocks
+/// themselves are fetched concurrently.
+pub(crate) async fn next_blocks_of<Block, P>(
+	provider: &P,
+	number: u64,
+	count: u64,
+) -> sc_cli::Result<Vec<(Block::Hash, Block)>>
+where
+	Block: BlockT,
+	P: BlockProvider<Block> + Sync,
+{
+	let hashes = provider.hashes_of((number + 1..=number + count).collect()).await?;
+	log::info!(target: LOG_TARGET, "fetching next blocks: {:?}", hashes);
+
+	let blocks =
+		futures::future::try_join_all(hashes.iter().map(|hash| provider.block_of(*hash))).await?;
+
+	Ok(hashes.into_iter().zip(blocks).collect())
+}
+
 pub(crate) async fn next_hash_of<Block: BlockT>(
 	rpc: &substrate_rpc_client::WsClient,
 	hash: Block::Hash,
@@ -162,9 +408,79 @@ where
 	.await
 	.map_err(rpc_err_handler)?
 	{
-		ListOrValue::Value(t) => t.expect("value passed in; value comes out; qed"),
-		_ => unreachable!(),
+		ListOrValue::Value(maybe_hash) => maybe_hash.ok_or("block_not_found")?,
+		ListOrValue::List(_) => return Err("expected a single block hash".into()),
 	};
 
 	Ok(next_hash)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::testing::{Block as TBlock, ExtrinsicWrapper, Header, H256};
+	use std::{sync::Mutex, time::Duration};
+
+	type Block = TBlock<ExtrinsicWrapper<()>>;
+
+	/// Serves block `n` with hash `n`, answering requests for lower blocks last.
+	#[derive(Default)]
+	struct MockBlockProvider {
+		served: Mutex<Vec<u64>>,
+	}
+
+	#[async_trait]
+	impl BlockProvider<Block> for MockBlockProvider {
+		async fn hashes_of(&self, numbers: Vec<u64>) -> sc_cli::Result<Vec<H256>> {
+			Ok(numbers.into_iter().map(H256::from_low_u64_be).collect())
+		}
+
+		async fn block_of(&self, hash: H256) -> sc_cli::Result<Block> {
+			let number = hash.to_low_u64_be();
+			tokio::time::sleep(Duration::from_millis(10 * (20 - number))).await;
+			self.served.lock().unwrap().push(number);
+			Ok(Block::new(Header::new_from_number(number), vec![]))
+		}
+	}
+
+	fn live_state(pallet: &[&str], child_tree: bool) -> LiveState {
+		LiveState {
+			uri: "ws://localhost:9944".into(),
+			at: None,
+			pallet: pallet.iter().map(|p| p.to_string()).collect(),
+			child_tree,
+		}
+	}
+
+	#[test]
+	fn cached_snapshot_path_depends_on_filters() {
+		let cache = Path::new("cache");
+		let path = |live: LiveState| cached_snapshot_path(cache, &live, "0x01");
+
+		assert_eq!(
+			path(live_state(&["System", "Balances"], false)),
+			path(live_state(&["Balances", "System"], false))
+		);
+		assert_ne!(path(live_state(&[], false)), path(live_state(&["System"], false)));
+		assert_ne!(path(live_state(&["System"], false)), path(live_state(&["System"], true)));
+		assert_ne!(path(live_state(&["System"], false)), path(live_state(&["Balances"], false)));
+	}
+
+	#[tokio::test]
+	async fn next_blocks_of_keeps_chain_order() {
+		let provider = MockBlockProvider::default();
+
+		let blocks = next_blocks_of::<Block, _>(&provider, 4, 5).await.unwrap();
+
+		// The blocks were fetched concurrently, so the last one was served first...
+		assert_eq!(*provider.served.lock().unwrap(), vec![9, 8, 7, 6, 5]);
+		// ...yet they come back in the order they have to be executed in.
+		assert_eq!(
+			blocks
+				.iter()
+				.map(|(hash, block)| (hash.to_low_u64_be(), block.header.number))
+				.collect::<Vec<_>>(),
+			vec![(5, 5), (6, 6), (7, 7), (8, 8), (9, 9)],
+		);
+	}
+}
@@ -36,6 +36,14 @@ pub(crate) fn hash(block_hash: &str) -> Result<String, String> {
 	}
 }
 
+pub(crate) fn number_or_hash(block: &str) -> Result<String, String> {
+	if block.parse::<u64>().is_ok() {
+		Ok(block.into())
+	} else {
+		hash(block)
+	}
+}
+
 pub(crate) fn url(s: &str) -> Result<String, &'static str> {
 	if s.starts_with("ws://") || s.starts_with("wss://") {
 		// could use Url crate as well, but lets keep it simple for now.
@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(unix)]
+#![cfg(feature = "try-runtime")]
+
+#[cfg(test)]
+mod tests {
+	use assert_cmd::cargo::cargo_bin;
+	use node_primitives::Hash;
+	use regex::Regex;
+	use std::{process, time::Duration};
+	use substrate_cli_test_utils as common;
+	use tokio::process::{Child, Command};
+
+	fn execute_block(ws_url: &str, at: Hash, shared_args: &[&str], args: &[&str]) -> Child {
+		Command::new(cargo_bin("substrate-node"))
+			.stdout(process::Stdio::piped())
+			.stderr(process::Stdio::piped())
+			.args(&["try-runtime", "--runtime=existing"])
+			.args(shared_args)
+			.args(&["execute-block"])
+			.args(args)
+			.args(&["live", format!("--uri={}", ws_url).as_str()])
+			.args(&["--at", format!("{:?}", at).as_str()])
+			.kill_on_drop(true)
+			.spawn()
+			.unwrap()
+	}
+
+	#[tokio::test]
+	async fn block_range_execution_works() {
+		// Build substrate so binaries used in the test use the latest code.
+		common::build_substrate(&["--features=try-runtime"]);
+
+		common::run_with_timeout(Duration::from_secs(120), async move {
+			// Start a node and wait for it to begin finalizing blocks.
+			let mut node = common::KillChildOnDrop(common::start_node());
+			let ws_url = common::extract_info_from_output(node.stderr.take().unwrap()).0.ws_url;
+			common::wait_n_finalized_blocks(4, &ws_url).await;
+
+			let block_hash = common::block_hash(1, &ws_url).await.unwrap();
+			let proofs = tempfile::tempdir().unwrap();
+			let proofs_path = proofs.path().join("proofs");
+
+			// Execute blocks 2 and 3 on top of the state of block 1.
+			let mut block_execution = execute_block(
+				&ws_url,
+				block_hash,
+				&[format!("--export-proof={}", proofs_path.display()).as_str()],
+				&["--to-block=3"],
+			);
+
+			let re = Regex::new(r#".*executed block 3, new storage root"#).unwrap();
+			let matched =
+				common::wait_for_stream_pattern_match(block_execution.stderr.take().unwrap(), re)
+					.await;
+			assert!(matched.is_ok());
+
+			// One proof file was written per executed block.
+			assert_eq!(std::fs::read_dir(&proofs_path).unwrap().count(), 2);
+		})
+		.await;
+	}
+
+	#[tokio::test]
+	async fn warm_snapshot_cache_needs_no_state_rpc() {
+		// Build substrate so binaries used in the test use the latest code.
+		common::build_substrate(&["--features=try-runtime"]);
+
+		common::run_with_timeout(Duration::from_secs(120), async move {
+			// Start a node and wait for it to begin finalizing blocks.
+			let mut node = common::KillChildOnDrop(common::start_node());
+			let ws_url = common::extract_info_from_output(node.stderr.take().unwrap()).0.ws_url;
+			common::wait_n_finalized_blocks(3, &ws_url).await;
+
+			let block_hash = common::block_hash(1, &ws_url).await.unwrap();
+			let cache = tempfile::tempdir().unwrap();
+			let cache_arg = format!("--snapshot-cache={}", cache.path().display());
+			let block_ws_uri = format!("--block-ws-uri={}", ws_url);
+
+			// The first run fetches the state and fills the cache.
+			let mut block_execution = execute_block(&ws_url, block_hash, &[], &[&cache_arg]);
+			let re = Regex::new(r#".*Block #2 successfully executed"#).unwrap();
+			let matched =
+				common::wait_for_stream_pattern_match(block_execution.stderr.take().unwrap(), re)
+					.await;
+			assert!(matched.is_ok());
+
+			// The second run reads the state from the cache only: its state uri is unreachable.
+			let mut block_execution =
+				execute_block("ws://127.0.0.1:1", block_hash, &[], &[&cache_arg, &block_ws_uri]);
+			let re = Regex::new(r#".*Block #2 successfully executed"#).unwrap();
+			let matched =
+				common::wait_for_stream_pattern_match(block_execution.stderr.take().unwrap(), re)
+					.await;
+			assert!(matched.is_ok());
+		})
+		.await;
+	}
+}